@@ -0,0 +1,336 @@
+//! Blending and compositing from the [Compositing and Blending Level 1 specification](https://drafts.fxtf.org/compositing-1/).
+
+use crate::{Color, ColorComponents, ColorSpace};
+
+/// The blend modes that determine how the source color is mixed with the
+/// backdrop where they overlap.
+/// https://drafts.fxtf.org/compositing-1/#blending
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+/// The Porter-Duff compositing operators.
+/// https://drafts.fxtf.org/compositing-1/#porterduffcompositingoperators
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CompositeOperator {
+    Clear,
+    Copy,
+    Destination,
+    #[default]
+    SourceOver,
+    DestinationOver,
+    SourceIn,
+    DestinationIn,
+    SourceOut,
+    DestinationOut,
+    SourceAtop,
+    DestinationAtop,
+    Xor,
+    Lighter,
+}
+
+impl BlendMode {
+    /// Returns true if the blend mode is applied to each channel separately.
+    pub fn is_separable(&self) -> bool {
+        !matches!(
+            self,
+            BlendMode::Hue | BlendMode::Saturation | BlendMode::Color | BlendMode::Luminosity
+        )
+    }
+
+    /// The blend function `B(Cb, Cs)` for the backdrop and source colors.
+    fn blend(&self, cb: &ColorComponents, cs: &ColorComponents) -> ColorComponents {
+        match self {
+            BlendMode::Hue => set_lum(&set_sat(cs, sat(cb)), lum(cb)),
+            BlendMode::Saturation => set_lum(&set_sat(cb, sat(cs)), lum(cb)),
+            BlendMode::Color => set_lum(cs, lum(cb)),
+            BlendMode::Luminosity => set_lum(cb, lum(cs)),
+            _ => ColorComponents(
+                self.blend_channel(cb.0, cs.0),
+                self.blend_channel(cb.1, cs.1),
+                self.blend_channel(cb.2, cs.2),
+            ),
+        }
+    }
+
+    fn blend_channel(&self, cb: f32, cs: f32) -> f32 {
+        match self {
+            BlendMode::Normal => cs,
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => cb + cs - cb * cs,
+            BlendMode::Overlay => BlendMode::HardLight.blend_channel(cs, cb),
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::ColorDodge => {
+                if cb == 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            BlendMode::HardLight => {
+                if cs <= 0.5 {
+                    BlendMode::Multiply.blend_channel(cb, 2.0 * cs)
+                } else {
+                    BlendMode::Screen.blend_channel(cb, 2.0 * cs - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        cb.sqrt()
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::Exclusion => cb + cs - 2.0 * cb * cs,
+            BlendMode::Hue | BlendMode::Saturation | BlendMode::Color | BlendMode::Luminosity => {
+                unreachable!("non-separable blend modes are blended per color")
+            }
+        }
+    }
+}
+
+impl CompositeOperator {
+    /// The fractions `Fa` and `Fb` of the source and backdrop that contribute
+    /// to the result.
+    fn fractions(&self, alpha_s: f32, alpha_b: f32) -> (f32, f32) {
+        match self {
+            CompositeOperator::Clear => (0.0, 0.0),
+            CompositeOperator::Copy => (1.0, 0.0),
+            CompositeOperator::Destination => (0.0, 1.0),
+            CompositeOperator::SourceOver => (1.0, 1.0 - alpha_s),
+            CompositeOperator::DestinationOver => (1.0 - alpha_b, 1.0),
+            CompositeOperator::SourceIn => (alpha_b, 0.0),
+            CompositeOperator::DestinationIn => (0.0, alpha_s),
+            CompositeOperator::SourceOut => (1.0 - alpha_b, 0.0),
+            CompositeOperator::DestinationOut => (0.0, 1.0 - alpha_s),
+            CompositeOperator::SourceAtop => (alpha_b, 1.0 - alpha_s),
+            CompositeOperator::DestinationAtop => (1.0 - alpha_b, alpha_s),
+            CompositeOperator::Xor => (1.0 - alpha_b, 1.0 - alpha_s),
+            CompositeOperator::Lighter => (1.0, 1.0),
+        }
+    }
+}
+
+impl Color {
+    /// Blend this color (the source) with the `backdrop` using the blend mode
+    /// and then composite the result onto the backdrop with the Porter-Duff
+    /// operator.
+    ///
+    /// Blending is performed on the components in `space`, which should be
+    /// an RGB color space such as [ColorSpace::Srgb] or
+    /// [ColorSpace::SrgbLinear]. The result is in `space`.
+    /// https://drafts.fxtf.org/compositing-1/#generalformula
+    pub fn composite(
+        &self,
+        backdrop: &Color,
+        blend_mode: BlendMode,
+        operator: CompositeOperator,
+        space: ColorSpace,
+    ) -> Self {
+        let source = self.clone().into_color_space(space);
+        let backdrop = backdrop.clone().into_color_space(space);
+
+        let (cs, alpha_s) = (source.components(), source.alpha().clamp(0.0, 1.0));
+        let (cb, alpha_b) = (backdrop.components(), backdrop.alpha().clamp(0.0, 1.0));
+
+        // Mix the source with the blended color where the backdrop is opaque.
+        let blended = blend_mode.blend(cb, cs);
+        let mix = |s: f32, b: f32| (1.0 - alpha_b) * s + alpha_b * b;
+        let cs = ColorComponents(
+            mix(cs.0, blended.0),
+            mix(cs.1, blended.1),
+            mix(cs.2, blended.2),
+        );
+
+        // Composite the premultiplied values.
+        let (fa, fb) = operator.fractions(alpha_s, alpha_b);
+        let mut alpha_o = alpha_s * fa + alpha_b * fb;
+        let co = |s: f32, b: f32| alpha_s * fa * s + alpha_b * fb * b;
+        let mut premultiplied = ColorComponents(co(cs.0, cb.0), co(cs.1, cb.1), co(cs.2, cb.2));
+
+        if operator == CompositeOperator::Lighter {
+            alpha_o = alpha_o.min(1.0);
+            premultiplied = premultiplied.copy_and_apply(|value| value.min(1.0));
+        }
+
+        let components = if alpha_o > 0.0 {
+            ColorComponents(
+                premultiplied.0 / alpha_o,
+                premultiplied.1 / alpha_o,
+                premultiplied.2 / alpha_o,
+            )
+        } else {
+            ColorComponents(0.0, 0.0, 0.0)
+        };
+
+        Color::new(space, components).with_alpha(alpha_o)
+    }
+}
+
+fn lum(c: &ColorComponents) -> f32 {
+    0.3 * c.0 + 0.59 * c.1 + 0.11 * c.2
+}
+
+fn clip_color(c: &ColorComponents) -> ColorComponents {
+    let l = lum(c);
+    let n = c.0.min(c.1).min(c.2);
+    let x = c.0.max(c.1).max(c.2);
+
+    let mut result = c.clone();
+    if n < 0.0 {
+        let scale = |v: f32| l + (v - l) * l / (l - n);
+        result = ColorComponents(scale(result.0), scale(result.1), scale(result.2));
+    }
+    if x > 1.0 {
+        let scale = |v: f32| l + (v - l) * (1.0 - l) / (x - l);
+        result = ColorComponents(scale(result.0), scale(result.1), scale(result.2));
+    }
+    result
+}
+
+fn set_lum(c: &ColorComponents, l: f32) -> ColorComponents {
+    let d = l - lum(c);
+    clip_color(&ColorComponents(c.0 + d, c.1 + d, c.2 + d))
+}
+
+fn sat(c: &ColorComponents) -> f32 {
+    c.0.max(c.1).max(c.2) - c.0.min(c.1).min(c.2)
+}
+
+fn set_sat(c: &ColorComponents, s: f32) -> ColorComponents {
+    let mut values = [c.0, c.1, c.2];
+
+    // Find the indices of the maximum, middle and minimum channels.
+    let mut order = [0, 1, 2];
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+    let [min, mid, max] = order;
+
+    if values[max] > values[min] {
+        values[mid] = (values[mid] - values[min]) * s / (values[max] - values[min]);
+        values[max] = s;
+    } else {
+        values[mid] = 0.0;
+        values[max] = 0.0;
+    }
+    values[min] = 0.0;
+
+    ColorComponents(values[0], values[1], values[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn srgb(r: f32, g: f32, b: f32, alpha: f32) -> Color {
+        Color::new(ColorSpace::Srgb, ColorComponents(r, g, b)).with_alpha(alpha)
+    }
+
+    fn assert_color(color: &Color, expected: [f32; 4]) {
+        let ColorComponents(r, g, b) = color.components().clone();
+        let actual = [r, g, b, color.alpha()];
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-4, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn separable_blend_modes() {
+        let source = srgb(0.25, 0.5, 0.75, 1.0);
+        let backdrop = srgb(0.5, 0.5, 0.5, 1.0);
+
+        let expected = [
+            (BlendMode::Normal, [0.25, 0.5, 0.75]),
+            (BlendMode::Multiply, [0.125, 0.25, 0.375]),
+            (BlendMode::Screen, [0.625, 0.75, 0.875]),
+            (BlendMode::Overlay, [0.25, 0.5, 0.75]),
+            (BlendMode::Darken, [0.25, 0.5, 0.5]),
+            (BlendMode::Lighten, [0.5, 0.5, 0.75]),
+            (BlendMode::Difference, [0.25, 0.0, 0.25]),
+            (BlendMode::Exclusion, [0.5, 0.5, 0.5]),
+        ];
+
+        for (mode, [r, g, b]) in expected {
+            let result = source.composite(
+                &backdrop,
+                mode,
+                CompositeOperator::SourceOver,
+                ColorSpace::Srgb,
+            );
+            assert_color(&result, [r, g, b, 1.0]);
+        }
+    }
+
+    #[test]
+    fn luminosity_keeps_backdrop_hue() {
+        let source = srgb(0.5, 0.5, 0.5, 1.0);
+        let backdrop = srgb(1.0, 0.0, 0.0, 1.0);
+
+        let result = source.composite(
+            &backdrop,
+            BlendMode::Luminosity,
+            CompositeOperator::SourceOver,
+            ColorSpace::Srgb,
+        );
+
+        let ColorComponents(r, g, b) = result.components().clone();
+        assert!((lum(&ColorComponents(r, g, b)) - 0.5).abs() < 1e-4);
+        assert!(r > g && g == b);
+    }
+
+    #[test]
+    fn porter_duff_operators() {
+        let source = srgb(1.0, 0.0, 0.0, 0.5);
+        let backdrop = srgb(0.0, 0.0, 1.0, 1.0);
+
+        let expected = [
+            (CompositeOperator::Clear, [0.0, 0.0, 0.0, 0.0]),
+            (CompositeOperator::Copy, [1.0, 0.0, 0.0, 0.5]),
+            (CompositeOperator::Destination, [0.0, 0.0, 1.0, 1.0]),
+            (CompositeOperator::SourceOver, [0.5, 0.0, 0.5, 1.0]),
+            (CompositeOperator::DestinationOver, [0.0, 0.0, 1.0, 1.0]),
+            (CompositeOperator::SourceIn, [1.0, 0.0, 0.0, 0.5]),
+            (CompositeOperator::DestinationOut, [0.0, 0.0, 1.0, 0.5]),
+            (CompositeOperator::Xor, [0.0, 0.0, 1.0, 0.5]),
+        ];
+
+        for (operator, rgba) in expected {
+            let result = source.composite(&backdrop, BlendMode::Normal, operator, ColorSpace::Srgb);
+            assert_color(&result, rgba);
+        }
+    }
+}
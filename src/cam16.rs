@@ -0,0 +1,442 @@
+//! The CAM16 color appearance model and its uniform color space CAM16-UCS.
+//! https://doi.org/10.1002/col.22131
+//!
+//! The model is evaluated in double precision, so that the HCT solver built
+//! on it matches Material Color Utilities.
+
+#![allow(clippy::excessive_precision)]
+
+use crate::convert::lightness_to_luminance;
+use crate::white_point::{multiply_vector, Matrix};
+use crate::{Color, ColorComponents, ColorSpace, WhitePoint};
+use std::f64::consts::PI;
+use std::sync::OnceLock;
+
+pub(crate) const XYZ_TO_CAM16_RGB: Matrix = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+const CAM16_RGB_TO_XYZ: Matrix = [
+    [1.8620678550872327, -1.0112546305316843, 0.14918677544445172],
+    [
+        0.38752654323613717,
+        0.6214474419314754,
+        -0.008973985167612518,
+    ],
+    [
+        -0.015841498849333856,
+        -0.03412293802851556,
+        1.0499644368778493,
+    ],
+];
+
+/// The relative luminance of the surround of the viewing field.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Surround {
+    /// Viewing surface colors, like a print under normal lighting.
+    #[default]
+    Average,
+    /// Viewing a television or monitor in a dim room.
+    Dim,
+    /// Viewing a projector in a dark room.
+    Dark,
+}
+
+impl Surround {
+    /// The factor for the degree of adaptation (F), the impact of the
+    /// surround (c) and the chromatic induction factor (Nc).
+    fn parameters(&self) -> (f64, f64, f64) {
+        match self {
+            Surround::Average => (1.0, 0.69, 1.0),
+            Surround::Dim => (0.9, 0.59, 0.9),
+            Surround::Dark => (0.8, 0.525, 0.8),
+        }
+    }
+}
+
+/// The conditions a color is viewed in, with the parameters of the model
+/// that only depend on them.
+#[derive(Clone, Debug)]
+pub struct ViewingConditions {
+    white_point: ColorComponents,
+    adapting_luminance: f32,
+    background_lightness: f32,
+    surround: Surround,
+
+    pub(crate) c: f64,
+    pub(crate) nc: f64,
+    pub(crate) n: f64,
+    pub(crate) z: f64,
+    pub(crate) nbb: f64,
+    pub(crate) fl: f64,
+    pub(crate) aw: f64,
+    pub(crate) rgb_d: [f64; 3],
+}
+
+impl ViewingConditions {
+    /// Create viewing conditions for the XYZ white point (with a luminance of
+    /// 1), the luminance of the adapting field in cd/m², and the CIE L* of
+    /// the background.
+    pub fn new(
+        white_point: ColorComponents,
+        adapting_luminance: f32,
+        background_lightness: f32,
+        surround: Surround,
+    ) -> Self {
+        let ColorComponents(x, y, z) = white_point;
+        Self::with_white(
+            [x as f64, y as f64, z as f64],
+            adapting_luminance as f64,
+            background_lightness,
+            surround,
+        )
+    }
+
+    /// Create viewing conditions for a white point in double precision.
+    pub(crate) fn with_white(
+        white_point: [f64; 3],
+        adapting_luminance: f64,
+        background_lightness: f32,
+        surround: Surround,
+    ) -> Self {
+        let (f, c, nc) = surround.parameters();
+        let la = adapting_luminance;
+
+        let white = white_point.map(|v| v * 100.0);
+        let rgb_w = multiply_vector(&XYZ_TO_CAM16_RGB, white);
+
+        let d = (f * (1.0 - (1.0 / 3.6) * ((-la - 42.0) / 92.0).exp())).clamp(0.0, 1.0);
+        let rgb_d = rgb_w.map(|w| d * white[1] / w + 1.0 - d);
+
+        let k = 1.0 / (5.0 * la + 1.0);
+        let k4 = k.powi(4);
+        let fl = k4 * la + 0.1 * (1.0 - k4).powi(2) * (5.0 * la).cbrt();
+
+        let n = lightness_to_luminance(background_lightness.max(0.1)) as f64 / white_point[1];
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 / n.powf(0.2);
+
+        let [r, g, b] = [0, 1, 2].map(|i| adapt(rgb_d[i] * rgb_w[i], fl));
+        let aw = (2.0 * r + g + 0.05 * b) * nbb;
+
+        Self {
+            white_point: ColorComponents(
+                white_point[0] as f32,
+                white_point[1] as f32,
+                white_point[2] as f32,
+            ),
+            adapting_luminance: la as f32,
+            background_lightness,
+            surround,
+            c,
+            nc,
+            n,
+            z,
+            nbb,
+            fl,
+            aw,
+            rgb_d,
+        }
+    }
+
+    /// The white point of the adapting field.
+    pub fn white_point(&self) -> &ColorComponents {
+        &self.white_point
+    }
+
+    /// The luminance of the adapting field in cd/m².
+    pub fn adapting_luminance(&self) -> f32 {
+        self.adapting_luminance
+    }
+
+    /// The CIE L* of the background.
+    pub fn background_lightness(&self) -> f32 {
+        self.background_lightness
+    }
+
+    /// The surround of the viewing field.
+    pub fn surround(&self) -> Surround {
+        self.surround
+    }
+
+    /// The viewing conditions of a display with the given white point of 200
+    /// cd/m² on a mid-gray background with an average surround.
+    pub(crate) fn srgb_display(white_point: [f64; 3]) -> Self {
+        Self::with_white(
+            white_point,
+            200.0 / PI * lightness_to_luminance(50.0) as f64,
+            50.0,
+            Surround::Average,
+        )
+    }
+}
+
+impl Default for ViewingConditions {
+    /// The viewing conditions of sRGB: a D65 display of 200 cd/m² on a
+    /// mid-gray background with an average surround.
+    fn default() -> Self {
+        Self::srgb_display(WhitePoint::D65.xyz_f64())
+    }
+}
+
+/// The shared default viewing conditions, used by [ColorSpace::Cam16Ucs].
+pub(crate) fn default_viewing_conditions() -> &'static ViewingConditions {
+    static CONDITIONS: OnceLock<ViewingConditions> = OnceLock::new();
+    CONDITIONS.get_or_init(ViewingConditions::default)
+}
+
+/// Apply the post-adaptation non-linear response compression.
+pub(crate) fn adapt(component: f64, fl: f64) -> f64 {
+    let x = (fl * component.abs() / 100.0).powf(0.42);
+    component.signum() * 400.0 * x / (x + 27.13)
+}
+
+/// Invert [adapt].
+pub(crate) fn unadapt(component: f64, fl: f64) -> f64 {
+    let base = (27.13 * component.abs() / (400.0 - component.abs())).max(0.0);
+    component.signum() * 100.0 / fl * base.powf(1.0 / 0.42)
+}
+
+/// The eccentricity factor for a hue in radians.
+pub(crate) fn eccentricity(hue: f64) -> f64 {
+    0.25 * ((hue + 2.0).cos() + 3.8)
+}
+
+/// The appearance correlates of a color under some viewing conditions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cam16 {
+    lightness: f32,
+    chroma: f32,
+    hue: f32,
+    brightness: f32,
+    colorfulness: f32,
+    saturation: f32,
+}
+
+impl Cam16 {
+    /// Calculate the correlates of an XYZ color with a D65 white point.
+    pub(crate) fn from_xyz(xyz: &ColorComponents, conditions: &ViewingConditions) -> Self {
+        let ColorComponents(x, y, z) = *xyz;
+        Self::from_xyz_f64([x as f64, y as f64, z as f64], conditions)
+    }
+
+    /// Calculate the correlates of an XYZ color in double precision.
+    pub(crate) fn from_xyz_f64(xyz: [f64; 3], conditions: &ViewingConditions) -> Self {
+        let vc = conditions;
+
+        let rgb = multiply_vector(&XYZ_TO_CAM16_RGB, xyz.map(|v| v * 100.0));
+        let [red, green, blue] = [0, 1, 2].map(|i| adapt(vc.rgb_d[i] * rgb[i], vc.fl));
+
+        // Opponent color dimensions.
+        let a = (11.0 * red - 12.0 * green + blue) / 11.0;
+        let b = (red + green - 2.0 * blue) / 9.0;
+        let u = (20.0 * red + 20.0 * green + 21.0 * blue) / 20.0;
+        let p2 = (40.0 * red + 20.0 * green + blue) / 20.0;
+
+        let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+        let achromatic = p2 * vc.nbb;
+        let lightness = 100.0 * (achromatic / vc.aw).max(0.0).powf(vc.c * vc.z);
+        let brightness = 4.0 / vc.c * (lightness / 100.0).sqrt() * (vc.aw + 4.0) * vc.fl.powf(0.25);
+
+        let hue_prime = if hue < 20.14 { hue + 360.0 } else { hue };
+        let p1 = 50000.0 / 13.0 * eccentricity(hue_prime.to_radians()) * vc.nc * vc.nbb;
+        let t = p1 * a.hypot(b) / (u + 0.305);
+        let alpha = t.powf(0.9) * (1.64 - 0.29_f64.powf(vc.n)).powf(0.73);
+
+        let chroma = alpha * (lightness / 100.0).sqrt();
+        let colorfulness = chroma * vc.fl.powf(0.25);
+        let saturation = 50.0 * (alpha * vc.c / (vc.aw + 4.0)).sqrt();
+
+        Self {
+            lightness: lightness as f32,
+            chroma: chroma as f32,
+            hue: hue as f32,
+            brightness: brightness as f32,
+            colorfulness: colorfulness as f32,
+            saturation: saturation as f32,
+        }
+    }
+
+    /// Calculate all correlates from lightness (J), chroma (C) and hue
+    /// (h) in degrees.
+    pub fn from_jch(lightness: f32, chroma: f32, hue: f32, conditions: &ViewingConditions) -> Self {
+        Self::from_xyz(
+            &Self::jch_to_xyz(lightness, chroma, hue, conditions),
+            conditions,
+        )
+    }
+
+    /// Calculate all correlates from CAM16-UCS J', a' and b' coordinates.
+    pub fn from_ucs(ucs: &ColorComponents, conditions: &ViewingConditions) -> Self {
+        Self::from_xyz(&ucs_to_xyz(ucs, conditions), conditions)
+    }
+
+    /// The lightness (J) correlate.
+    pub fn lightness(&self) -> f32 {
+        self.lightness
+    }
+
+    /// The chroma (C) correlate.
+    pub fn chroma(&self) -> f32 {
+        self.chroma
+    }
+
+    /// The hue angle (h) in degrees.
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+
+    /// The brightness (Q) correlate.
+    pub fn brightness(&self) -> f32 {
+        self.brightness
+    }
+
+    /// The colorfulness (M) correlate.
+    pub fn colorfulness(&self) -> f32 {
+        self.colorfulness
+    }
+
+    /// The saturation (s) correlate.
+    pub fn saturation(&self) -> f32 {
+        self.saturation
+    }
+
+    /// The CAM16-UCS J', a' and b' coordinates.
+    pub fn ucs(&self) -> ColorComponents {
+        let lightness = 1.7 * self.lightness / (1.0 + 0.007 * self.lightness);
+        let colorfulness = (1.0 + 0.0228 * self.colorfulness).ln() / 0.0228;
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        ColorComponents(lightness, colorfulness * cos, colorfulness * sin)
+    }
+
+    /// The color with these correlates under the viewing conditions. The
+    /// result is in [ColorSpace::XyzD65].
+    pub fn to_color(&self, conditions: &ViewingConditions) -> Color {
+        let xyz = Self::jch_to_xyz(self.lightness, self.chroma, self.hue, conditions);
+        Color::new(ColorSpace::XyzD65, xyz)
+    }
+
+    /// Invert the model from lightness, chroma and hue to XYZ with a D65
+    /// white point.
+    pub(crate) fn jch_to_xyz(
+        lightness: f32,
+        chroma: f32,
+        hue: f32,
+        conditions: &ViewingConditions,
+    ) -> ColorComponents {
+        let [x, y, z] = Self::jch_to_xyz_f64(
+            lightness as f64,
+            chroma as f64,
+            (hue as f64).to_radians(),
+            conditions,
+        );
+        ColorComponents(x as f32, y as f32, z as f32)
+    }
+
+    /// The discounted CAM16 RGB (scaled by the degree of adaptation) of the
+    /// color with the lightness, chroma and hue in radians.
+    pub(crate) fn jch_to_discounted_rgb(
+        lightness: f64,
+        chroma: f64,
+        hue: f64,
+        conditions: &ViewingConditions,
+    ) -> [f64; 3] {
+        let vc = conditions;
+
+        let alpha = if chroma == 0.0 || lightness == 0.0 {
+            0.0
+        } else {
+            chroma / (lightness / 100.0).sqrt()
+        };
+        let t = (alpha / (1.64 - 0.29_f64.powf(vc.n)).powf(0.73)).powf(1.0 / 0.9);
+
+        let achromatic = vc.aw * (lightness / 100.0).max(0.0).powf(1.0 / vc.c / vc.z);
+        let p1 = eccentricity(hue) * 50000.0 / 13.0 * vc.nc * vc.nbb;
+        let p2 = achromatic / vc.nbb;
+
+        let (sin, cos) = hue.sin_cos();
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * cos + 108.0 * t * sin);
+        let a = gamma * cos;
+        let b = gamma * sin;
+
+        let red = (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0;
+        let green = (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0;
+        let blue = (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0;
+
+        [red, green, blue].map(|v| unadapt(v, vc.fl))
+    }
+
+    /// Invert the model in double precision, with the hue in radians.
+    pub(crate) fn jch_to_xyz_f64(
+        lightness: f64,
+        chroma: f64,
+        hue: f64,
+        conditions: &ViewingConditions,
+    ) -> [f64; 3] {
+        let discounted = Self::jch_to_discounted_rgb(lightness, chroma, hue, conditions);
+        let rgb = [0, 1, 2].map(|i| discounted[i] / conditions.rgb_d[i]);
+        multiply_vector(&CAM16_RGB_TO_XYZ, rgb).map(|v| v / 100.0)
+    }
+}
+
+/// Convert CAM16-UCS coordinates to XYZ with a D65 white point.
+pub(crate) fn ucs_to_xyz(ucs: &ColorComponents, conditions: &ViewingConditions) -> ColorComponents {
+    let ColorComponents(lightness, a, b) = *ucs;
+    let colorfulness = ((a.hypot(b) * 0.0228).exp() - 1.0) / 0.0228;
+    let chroma = colorfulness / (conditions.fl as f32).powf(0.25);
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+    let lightness = lightness / (1.7 - 0.007 * lightness);
+
+    Cam16::jch_to_xyz(lightness, chroma, hue, conditions)
+}
+
+impl Color {
+    /// The CAM16 appearance correlates of this color under the given viewing
+    /// conditions.
+    pub fn cam16(&self, conditions: &ViewingConditions) -> Cam16 {
+        let xyz = self.clone().into_color_space(ColorSpace::XyzD65);
+        Cam16::from_xyz(xyz.components(), conditions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn srgb_red() {
+        let red = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 0.0, 0.0));
+        let cam = red.cam16(&ViewingConditions::default());
+
+        // Reference values from Material Color Utilities.
+        assert!((cam.hue() - 27.408).abs() < 0.1, "{:?}", cam);
+        assert!((cam.chroma() - 113.357).abs() < 0.2, "{:?}", cam);
+        assert!((cam.lightness() - 46.445).abs() < 0.1, "{:?}", cam);
+        assert!((cam.colorfulness() - 89.494).abs() < 0.2, "{:?}", cam);
+        assert!((cam.saturation() - 91.889).abs() < 0.2, "{:?}", cam);
+        assert!((cam.brightness() - 105.988).abs() < 0.2, "{:?}", cam);
+    }
+
+    #[test]
+    fn round_trip() {
+        let conditions = ViewingConditions::new(WhitePoint::D65.xyz(), 64.0, 20.0, Surround::Dim);
+        let color = Color::new(ColorSpace::Srgb, ColorComponents(0.2, 0.6, 0.4));
+
+        let cam = color.cam16(&conditions);
+        let back = Cam16::from_jch(cam.lightness(), cam.chroma(), cam.hue(), &conditions)
+            .to_color(&conditions)
+            .into_color_space(ColorSpace::Srgb);
+        let back = back.components();
+
+        assert!(
+            (back.0 - 0.2).abs() < 1e-3
+                && (back.1 - 0.6).abs() < 1e-3
+                && (back.2 - 0.4).abs() < 1e-3,
+            "{:?}",
+            back
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use crate::convert;
-use crate::ColorSpace;
+use crate::{ChromaticAdaptation, ColorSpace};
+use std::ops::{Index, IndexMut};
 
 /// The three color components that represent a color.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ColorComponents(pub f32, pub f32, pub f32);
 
 impl ColorComponents {
@@ -12,7 +13,32 @@ impl ColorComponents {
     }
 }
 
+impl Index<usize> for ColorComponents {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            _ => panic!("color component index out of range: {}", index),
+        }
+    }
+}
+
+impl IndexMut<usize> for ColorComponents {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            _ => panic!("color component index out of range: {}", index),
+        }
+    }
+}
+
 /// An absolutely specified color.
+#[derive(Clone, Debug)]
 pub struct Color {
     components: ColorComponents,
     alpha: f32,
@@ -33,9 +59,66 @@ impl Color {
         self
     }
 
+    /// The color components in the color's color space.
+    pub fn components(&self) -> &ColorComponents {
+        &self.components
+    }
+
+    /// The straight (non-premultiplied) alpha of the color.
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// The color space the components are specified in.
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
     /// Convert this color to the specified color space.
     pub fn into_color_space(self, color_space: ColorSpace) -> Self {
         let result = convert::convert(self.color_space, &self.components, color_space);
         Self::new(color_space, result).with_alpha(self.alpha)
     }
+
+    /// Convert this color to the specified color space, adapting between white
+    /// points with the given chromatic adaptation transform instead of
+    /// Bradford.
+    pub fn into_color_space_with_adaptation(
+        self,
+        color_space: ColorSpace,
+        adaptation: ChromaticAdaptation,
+    ) -> Self {
+        let result = convert::convert_with_adaptation(
+            self.color_space,
+            &self.components,
+            color_space,
+            adaptation,
+        );
+        Self::new(color_space, result).with_alpha(self.alpha)
+    }
+
+    /// Returns true if this color can be displayed in the specified color
+    /// space without clipping. Color spaces without a bounded gamut always
+    /// return true.
+    pub fn is_in_gamut(&self, color_space: ColorSpace) -> bool {
+        const EPSILON: f32 = 0.000_1;
+
+        if !matches!(
+            color_space,
+            ColorSpace::Srgb
+                | ColorSpace::SrgbLinear
+                | ColorSpace::DisplayP3
+                | ColorSpace::A98Rgb
+                | ColorSpace::ProphotoRgb
+                | ColorSpace::Rec2020
+                | ColorSpace::Custom(_)
+        ) {
+            return true;
+        }
+
+        let ColorComponents(r, g, b) =
+            convert::convert(self.color_space, &self.components, color_space);
+        let range = -EPSILON..=1.0 + EPSILON;
+        range.contains(&r) && range.contains(&g) && range.contains(&b)
+    }
 }
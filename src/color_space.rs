@@ -1,5 +1,7 @@
+use crate::CustomColorSpace;
+
 /// The color space that color components can be specified in.
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ColorSpace {
     /// CIE L*a*b* color space.
@@ -14,6 +16,68 @@ pub enum ColorSpace {
     /// Polar form of [Oklab].
     /// https://w3c.github.io/csswg-drafts/css-color-4/#lch-colors
     Oklch,
+    /// CIE L*u*v* color space with a D65 white reference. Unlike [Lab], which
+    /// uses D50 as in CSS, CIELUV uses D65 to match HSLuv and data measured
+    /// relative to sRGB displays. [LuvD50] uses the white of [Lab].
+    /// http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html
+    Luv,
+    /// Polar form of [Luv].
+    /// http://www.brucelindbloom.com/index.html?Eqn_Luv_to_LCH.html
+    LchUv,
+    /// CIE L*u*v* color space with a D50 white reference, like [Lab].
+    /// http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html
+    LuvD50,
+    /// Polar form of [LuvD50].
+    /// http://www.brucelindbloom.com/index.html?Eqn_Luv_to_LCH.html
+    LchUvD50,
+    /// HSLuv, a human-friendly alternative to HSL built on [LchUv], with
+    /// components in the order hue, saturation and lightness. Saturation is
+    /// relative to the sRGB gamut at the given hue and lightness.
+    /// https://www.hsluv.org/math/
+    Hsluv,
+    /// HPLuv, a variant of [Hsluv] where saturation is relative to the
+    /// largest chroma that is in the sRGB gamut for all hues at the given
+    /// lightness.
+    /// https://www.hsluv.org/math/
+    Hpluv,
+    /// Okhsl, an HSL-like model built on [Oklab] with components in the order
+    /// hue, saturation and lightness. Saturation and lightness are in the
+    /// range [0 - 1] within the sRGB gamut.
+    /// https://bottosson.github.io/posts/colorpicker/#okhsl
+    Okhsl,
+    /// Okhsv, an HSV-like model built on [Oklab] with components in the order
+    /// hue, saturation and value. Saturation and value are in the range
+    /// [0 - 1] within the sRGB gamut.
+    /// https://bottosson.github.io/posts/colorpicker/#okhsv
+    Okhsv,
+    /// The HSV (also known as HSB) form of [Srgb] with components in the order
+    /// hue, saturation and value. Saturation and value are in the range
+    /// [0 - 1].
+    /// https://en.wikipedia.org/wiki/HSL_and_HSV
+    Hsv,
+    /// CAM16-UCS, the uniform color space of the CAM16 color appearance model
+    /// with components J', a' and b', under the default viewing conditions.
+    /// https://doi.org/10.1002/col.22131
+    Cam16Ucs,
+    /// HCT, the hue and chroma of [Cam16Ucs] with the CIE L* tone, with
+    /// components in the order hue, chroma and tone.
+    /// https://material.io/blog/science-of-color-design
+    Hct,
+    /// Jzazbz, a perceptually uniform color space for HDR imagery, with media
+    /// white at 203 cd/m².
+    /// https://doi.org/10.1364/OE.25.015131
+    Jzazbz,
+    /// Polar form of [Jzazbz].
+    /// https://doi.org/10.1364/OE.25.015131
+    Jzczhz,
+    /// ICtCp with the PQ transfer function, where media white is at
+    /// 203 cd/m².
+    /// https://www.itu.int/rec/R-REC-BT.2100
+    Ictcp,
+    /// ICtCp with the HLG transfer function, where media white is at an HLG
+    /// signal of 0.75.
+    /// https://www.itu.int/rec/R-REC-BT.2100
+    IctcpHlg,
     /// Industry standard sRGB color space.
     /// https://w3c.github.io/csswg-drafts/css-color-4/#predefined-sRGB
     Srgb,
@@ -36,6 +100,33 @@ pub enum ColorSpace {
     /// http://www.itu.int/rec/R-REC-BT.2020/en
     /// https://w3c.github.io/csswg-drafts/css-color-4/#predefined-rec2020
     Rec2020,
+    /// The "rec2100-pq" color space, with the Rec. 2020 primaries and the PQ
+    /// transfer function, where media white is at 203 cd/m².
+    /// https://drafts.csswg.org/css-color-hdr/#valdef-color-rec2100-pq
+    Rec2100Pq,
+    /// The "rec2100-hlg" color space, with the Rec. 2020 primaries and the HLG
+    /// transfer function, where media white is at an HLG signal of 0.75. The
+    /// OOTF uses the system gamma 1.2 of a 1000 cd/m² display.
+    /// https://drafts.csswg.org/css-color-hdr/#valdef-color-rec2100-hlg
+    Rec2100Hlg,
+    /// The "rec2100-linear" color space, with the Rec. 2020 primaries and
+    /// linear light, where media white is 1.
+    /// https://drafts.csswg.org/css-color-hdr/#valdef-color-rec2100-linear
+    Rec2100Linear,
+    /// The ACES2065-1 color space, with the AP0 primaries and linear light.
+    /// https://docs.acescentral.com/specifications/aces2065-1/
+    Aces2065_1,
+    /// The ACEScg color space, with the AP1 primaries and linear light.
+    /// https://docs.acescentral.com/specifications/acescg/
+    Acescg,
+    /// The ACEScc color space, with the AP1 primaries and a logarithmic
+    /// encoding.
+    /// https://docs.acescentral.com/specifications/acescc/
+    Acescc,
+    /// The ACEScct color space, with the AP1 primaries and a logarithmic
+    /// encoding with a linear toe.
+    /// https://docs.acescentral.com/specifications/acescct/
+    Acescct,
     /// The CIE XYZ color space with a D50 white reference.
     /// http://www.cie.co.at/publications/colorimetry-4th-edition
     /// https://w3c.github.io/csswg-drafts/css-color-4/#predefined-xyz
@@ -44,6 +135,8 @@ pub enum ColorSpace {
     /// http://www.cie.co.at/publications/colorimetry-4th-edition
     /// https://w3c.github.io/csswg-drafts/css-color-4/#predefined-xyz
     XyzD65,
+    /// An RGB color space registered with [crate::RgbColorSpace::register].
+    Custom(CustomColorSpace),
 }
 
 impl ColorSpace {
@@ -56,13 +149,46 @@ impl ColorSpace {
     /// Returns true if the color space represents a cylindrical polar color.
     #[inline]
     pub fn is_polar(&self) -> bool {
-        matches!(self, ColorSpace::Lch | ColorSpace::Oklch)
+        self.hue_index().is_some()
+    }
+
+    /// The index of the hue component for cylindrical polar color spaces.
+    /// The chroma (or saturation) of a polar color space is always the
+    /// component at index 1.
+    #[inline]
+    pub fn hue_index(&self) -> Option<usize> {
+        match self {
+            ColorSpace::Lch
+            | ColorSpace::Oklch
+            | ColorSpace::LchUv
+            | ColorSpace::LchUvD50
+            | ColorSpace::Jzczhz => Some(2),
+            ColorSpace::Hsluv
+            | ColorSpace::Hpluv
+            | ColorSpace::Okhsl
+            | ColorSpace::Okhsv
+            | ColorSpace::Hsv
+            | ColorSpace::Hct => Some(0),
+            _ => None,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{RgbColorSpace, RgbTransferFunction, WhitePoint};
+
+    fn srgb_custom() -> ColorSpace {
+        RgbColorSpace::new(
+            (0.64, 0.33),
+            (0.30, 0.60),
+            (0.15, 0.06),
+            WhitePoint::D65,
+            RgbTransferFunction::Srgb,
+        )
+        .register()
+    }
 
     #[test]
     fn is_polar_or_rectangular() {
@@ -71,14 +197,37 @@ mod tests {
             (ColorSpace::Lch, true),
             (ColorSpace::Oklab, false),
             (ColorSpace::Oklch, true),
+            (ColorSpace::Luv, false),
+            (ColorSpace::LchUv, true),
+            (ColorSpace::LuvD50, false),
+            (ColorSpace::LchUvD50, true),
+            (ColorSpace::Hsluv, true),
+            (ColorSpace::Hpluv, true),
+            (ColorSpace::Okhsl, true),
+            (ColorSpace::Okhsv, true),
+            (ColorSpace::Hsv, true),
+            (ColorSpace::Cam16Ucs, false),
+            (ColorSpace::Hct, true),
+            (ColorSpace::Jzazbz, false),
+            (ColorSpace::Jzczhz, true),
+            (ColorSpace::Ictcp, false),
+            (ColorSpace::IctcpHlg, false),
             (ColorSpace::Srgb, false),
             (ColorSpace::SrgbLinear, false),
             (ColorSpace::DisplayP3, false),
             (ColorSpace::A98Rgb, false),
             (ColorSpace::ProphotoRgb, false),
             (ColorSpace::Rec2020, false),
+            (ColorSpace::Rec2100Pq, false),
+            (ColorSpace::Rec2100Hlg, false),
+            (ColorSpace::Rec2100Linear, false),
+            (ColorSpace::Aces2065_1, false),
+            (ColorSpace::Acescg, false),
+            (ColorSpace::Acescc, false),
+            (ColorSpace::Acescct, false),
             (ColorSpace::XyzD50, false),
             (ColorSpace::XyzD65, false),
+            (srgb_custom(), false),
         ];
 
         for (color_space, is_polar) in expected {
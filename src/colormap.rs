@@ -0,0 +1,343 @@
+//! Perceptual colormaps for scientific visualization.
+//!
+//! The sequential maps are tabulated from polynomial approximations of the
+//! matplotlib and Google data, corrected to pass through ten reference
+//! samples of each map. Cividis is interpolated from its reference samples
+//! and coolwarm is generated with Moreland's diverging Msh interpolation.
+
+#![allow(clippy::approx_constant)]
+
+use crate::{Color, ColorComponents, ColorSpace, HueInterpolationMethod};
+
+/// A built-in colormap.
+///
+/// The tables of the sequential maps approximate the published data to about
+/// three decimal places, so sampled colors can differ by one 8-bit code value
+/// from the colors produced by matplotlib and other tools.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Colormap {
+    Viridis,
+    Magma,
+    Inferno,
+    Plasma,
+    Cividis,
+    Turbo,
+    /// Moreland's cool to warm diverging map.
+    Coolwarm,
+}
+
+/// A summary of the perceptual step sizes along a colormap, measured as the
+/// ΔEOK between consecutive samples.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UniformityReport {
+    /// The ΔEOK between each pair of consecutive samples.
+    pub steps: Vec<f32>,
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    pub std_dev: f32,
+}
+
+impl UniformityReport {
+    /// The standard deviation of the steps relative to their mean. A
+    /// perfectly uniform map has a value of 0.
+    pub fn coefficient_of_variation(&self) -> f32 {
+        if self.mean > 0.0 {
+            self.std_dev / self.mean
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Colormap {
+    /// All of the built-in colormaps.
+    pub const ALL: [Colormap; 7] = [
+        Colormap::Viridis,
+        Colormap::Magma,
+        Colormap::Inferno,
+        Colormap::Plasma,
+        Colormap::Cividis,
+        Colormap::Turbo,
+        Colormap::Coolwarm,
+    ];
+
+    fn table(&self) -> &'static [[f32; 3]] {
+        match self {
+            Colormap::Viridis => &VIRIDIS,
+            Colormap::Magma => &MAGMA,
+            Colormap::Inferno => &INFERNO,
+            Colormap::Plasma => &PLASMA,
+            Colormap::Cividis => &CIVIDIS,
+            Colormap::Turbo => &TURBO,
+            Colormap::Coolwarm => &COOLWARM,
+        }
+    }
+
+    /// The evenly spaced sRGB colors that make up the map.
+    pub fn colors(&self) -> Vec<Color> {
+        self.table()
+            .iter()
+            .map(|[r, g, b]| Color::new(ColorSpace::Srgb, ColorComponents(*r, *g, *b)))
+            .collect()
+    }
+
+    /// The color at `t`, which is clamped to [0 - 1]. Colors between the
+    /// entries of the table are interpolated in `color_space` and the result
+    /// is in `color_space`.
+    pub fn sample(&self, t: f32, color_space: ColorSpace) -> Color {
+        let table = self.table();
+        let segments = table.len() - 1;
+
+        let position = t.clamp(0.0, 1.0) * segments as f32;
+        let index = (position.floor() as usize).min(segments - 1);
+
+        let color = |[r, g, b]: [f32; 3]| Color::new(ColorSpace::Srgb, ColorComponents(r, g, b));
+        color(table[index]).interpolate(
+            &color(table[index + 1]),
+            position - index as f32,
+            color_space,
+            HueInterpolationMethod::Shorter,
+        )
+    }
+
+    /// Measure the perceptual uniformity of the map by taking `samples` evenly
+    /// spaced samples, interpolated in `color_space`.
+    pub fn uniformity(&self, samples: usize, color_space: ColorSpace) -> UniformityReport {
+        let samples = samples.max(2);
+        let colors: Vec<Color> = (0..samples)
+            .map(|i| self.sample(i as f32 / (samples - 1) as f32, color_space))
+            .collect();
+
+        let steps: Vec<f32> = colors.windows(2).map(|w| w[0].delta_e_ok(&w[1])).collect();
+
+        let count = steps.len() as f32;
+        let mean = steps.iter().sum::<f32>() / count;
+        let variance = steps.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / count;
+
+        UniformityReport {
+            min: steps.iter().copied().fold(f32::INFINITY, f32::min),
+            max: steps.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+            mean,
+            std_dev: variance.sqrt(),
+            steps,
+        }
+    }
+}
+
+#[rustfmt::skip]
+const VIRIDIS: [[f32; 3]; 65] = [
+    [0.2667, 0.0039, 0.3294], [0.2703, 0.0259, 0.3518], [0.2739, 0.0480, 0.3739],
+    [0.2773, 0.0699, 0.3954], [0.2801, 0.0915, 0.4159], [0.2821, 0.1129, 0.4351],
+    [0.2830, 0.1340, 0.4528], [0.2825, 0.1546, 0.4689], [0.2804, 0.1748, 0.4832],
+    [0.2766, 0.1944, 0.4956], [0.2716, 0.2136, 0.5064], [0.2655, 0.2323, 0.5156],
+    [0.2587, 0.2507, 0.5235], [0.2516, 0.2687, 0.5303], [0.2446, 0.2863, 0.5361],
+    [0.2379, 0.3037, 0.5411], [0.2310, 0.3210, 0.5453], [0.2240, 0.3380, 0.5488],
+    [0.2168, 0.3547, 0.5516], [0.2096, 0.3711, 0.5537], [0.2022, 0.3872, 0.5554],
+    [0.1947, 0.4027, 0.5566], [0.1873, 0.4178, 0.5574], [0.1806, 0.4324, 0.5579],
+    [0.1745, 0.4467, 0.5583], [0.1687, 0.4607, 0.5585], [0.1631, 0.4747, 0.5584],
+    [0.1575, 0.4889, 0.5580], [0.1517, 0.5033, 0.5573], [0.1455, 0.5181, 0.5562],
+    [0.1390, 0.5334, 0.5547], [0.1326, 0.5490, 0.5527], [0.1270, 0.5647, 0.5504],
+    [0.1227, 0.5805, 0.5475], [0.1203, 0.5961, 0.5440], [0.1203, 0.6113, 0.5399],
+    [0.1232, 0.6261, 0.5350], [0.1287, 0.6405, 0.5290], [0.1366, 0.6546, 0.5220],
+    [0.1471, 0.6684, 0.5139], [0.1602, 0.6820, 0.5047], [0.1759, 0.6955, 0.4943],
+    [0.1942, 0.7088, 0.4828], [0.2152, 0.7220, 0.4702], [0.2394, 0.7350, 0.4564],
+    [0.2668, 0.7477, 0.4415], [0.2970, 0.7601, 0.4251], [0.3294, 0.7722, 0.4072],
+    [0.3636, 0.7839, 0.3877], [0.3992, 0.7953, 0.3666], [0.4356, 0.8064, 0.3438],
+    [0.4722, 0.8171, 0.3196], [0.5092, 0.8276, 0.2944], [0.5471, 0.8377, 0.2684],
+    [0.5859, 0.8472, 0.2424], [0.6260, 0.8560, 0.2169], [0.6676, 0.8641, 0.1925],
+    [0.7108, 0.8713, 0.1702], [0.7550, 0.8778, 0.1506], [0.7994, 0.8835, 0.1346],
+    [0.8432, 0.8886, 0.1231], [0.8853, 0.8932, 0.1171], [0.9248, 0.8975, 0.1178],
+    [0.9608, 0.9017, 0.1267], [0.9922, 0.9059, 0.1451],
+];
+
+#[rustfmt::skip]
+const MAGMA: [[f32; 3]; 65] = [
+    [0.0000, 0.0000, 0.0157], [0.0030, 0.0111, 0.0443], [0.0116, 0.0220, 0.0774],
+    [0.0230, 0.0318, 0.1099], [0.0370, 0.0405, 0.1420], [0.0533, 0.0479, 0.1741],
+    [0.0716, 0.0540, 0.2064], [0.0918, 0.0584, 0.2394], [0.1137, 0.0609, 0.2738],
+    [0.1373, 0.0614, 0.3094], [0.1620, 0.0608, 0.3451], [0.1875, 0.0597, 0.3794],
+    [0.2133, 0.0591, 0.4113], [0.2393, 0.0595, 0.4393], [0.2649, 0.0619, 0.4623],
+    [0.2902, 0.0666, 0.4794], [0.3152, 0.0733, 0.4911], [0.3400, 0.0814, 0.4984],
+    [0.3646, 0.0904, 0.5024], [0.3893, 0.1000, 0.5042], [0.4139, 0.1096, 0.5049],
+    [0.4387, 0.1187, 0.5055], [0.4636, 0.1272, 0.5068], [0.4883, 0.1359, 0.5080],
+    [0.5129, 0.1447, 0.5086], [0.5375, 0.1536, 0.5085], [0.5621, 0.1626, 0.5072],
+    [0.5870, 0.1715, 0.5047], [0.6122, 0.1804, 0.5005], [0.6378, 0.1891, 0.4947],
+    [0.6637, 0.1973, 0.4887], [0.6897, 0.2055, 0.4822], [0.7156, 0.2138, 0.4752],
+    [0.7412, 0.2228, 0.4675], [0.7663, 0.2327, 0.4588], [0.7907, 0.2440, 0.4491],
+    [0.8143, 0.2569, 0.4380], [0.8372, 0.2709, 0.4255], [0.8594, 0.2861, 0.4122],
+    [0.8806, 0.3026, 0.3990], [0.9005, 0.3206, 0.3867], [0.9188, 0.3401, 0.3761],
+    [0.9352, 0.3613, 0.3682], [0.9496, 0.3844, 0.3636], [0.9615, 0.4101, 0.3621],
+    [0.9710, 0.4380, 0.3634], [0.9785, 0.4676, 0.3675], [0.9842, 0.4983, 0.3741],
+    [0.9887, 0.5296, 0.3830], [0.9922, 0.5606, 0.3940], [0.9922, 0.5909, 0.4070],
+    [0.9930, 0.6198, 0.4221], [0.9948, 0.6476, 0.4395], [0.9972, 0.6751, 0.4592],
+    [0.9997, 0.7027, 0.4809], [1.0000, 0.7310, 0.5044], [0.9992, 0.7605, 0.5295],
+    [0.9956, 0.7918, 0.5559], [0.9910, 0.8244, 0.5833], [0.9861, 0.8572, 0.6114],
+    [0.9818, 0.8895, 0.6399], [0.9789, 0.9201, 0.6684], [0.9783, 0.9482, 0.6964],
+    [0.9811, 0.9726, 0.7235], [0.9882, 0.9922, 0.7490],
+];
+
+#[rustfmt::skip]
+const INFERNO: [[f32; 3]; 65] = [
+    [0.0000, 0.0000, 0.0157], [0.0039, 0.0102, 0.0495], [0.0125, 0.0192, 0.0953],
+    [0.0251, 0.0272, 0.1348], [0.0409, 0.0339, 0.1695], [0.0595, 0.0395, 0.2005],
+    [0.0804, 0.0439, 0.2289], [0.1032, 0.0468, 0.2559], [0.1277, 0.0480, 0.2826],
+    [0.1534, 0.0475, 0.3095], [0.1800, 0.0461, 0.3357], [0.2071, 0.0446, 0.3605],
+    [0.2344, 0.0436, 0.3830], [0.2615, 0.0439, 0.4021], [0.2883, 0.0462, 0.4170],
+    [0.3143, 0.0510, 0.4270], [0.3398, 0.0581, 0.4330], [0.3647, 0.0667, 0.4358],
+    [0.3892, 0.0765, 0.4360], [0.4135, 0.0868, 0.4344], [0.4379, 0.0971, 0.4316],
+    [0.4624, 0.1068, 0.4285], [0.4871, 0.1156, 0.4252], [0.5120, 0.1239, 0.4209],
+    [0.5370, 0.1321, 0.4154], [0.5620, 0.1403, 0.4087], [0.5869, 0.1489, 0.4008],
+    [0.6117, 0.1580, 0.3917], [0.6362, 0.1678, 0.3814], [0.6605, 0.1786, 0.3701],
+    [0.6848, 0.1900, 0.3580], [0.7088, 0.2020, 0.3453], [0.7326, 0.2146, 0.3316],
+    [0.7558, 0.2281, 0.3169], [0.7784, 0.2424, 0.3012], [0.8001, 0.2577, 0.2844],
+    [0.8208, 0.2741, 0.2665], [0.8403, 0.2918, 0.2489], [0.8586, 0.3106, 0.2315],
+    [0.8757, 0.3306, 0.2140], [0.8917, 0.3515, 0.1962], [0.9067, 0.3735, 0.1777],
+    [0.9206, 0.3962, 0.1585], [0.9336, 0.4197, 0.1381], [0.9454, 0.4443, 0.1152],
+    [0.9559, 0.4700, 0.0911], [0.9649, 0.4966, 0.0679], [0.9723, 0.5242, 0.0476],
+    [0.9781, 0.5524, 0.0323], [0.9823, 0.5812, 0.0239], [0.9847, 0.6104, 0.0246],
+    [0.9856, 0.6395, 0.0356], [0.9850, 0.6685, 0.0560], [0.9832, 0.6977, 0.0840],
+    [0.9803, 0.7272, 0.1181], [0.9766, 0.7572, 0.1566], [0.9724, 0.7879, 0.1978],
+    [0.9682, 0.8192, 0.2400], [0.9644, 0.8509, 0.2836], [0.9616, 0.8824, 0.3303],
+    [0.9604, 0.9128, 0.3811], [0.9616, 0.9417, 0.4369], [0.9659, 0.9681, 0.4987],
+    [0.9745, 0.9912, 0.5672], [0.9882, 1.0000, 0.6431],
+];
+
+#[rustfmt::skip]
+const PLASMA: [[f32; 3]; 65] = [
+    [0.0510, 0.0314, 0.5294], [0.0862, 0.0334, 0.5423], [0.1205, 0.0328, 0.5561],
+    [0.1537, 0.0304, 0.5703], [0.1859, 0.0266, 0.5844], [0.2170, 0.0220, 0.5980],
+    [0.2468, 0.0172, 0.6108], [0.2753, 0.0123, 0.6223], [0.3024, 0.0076, 0.6325],
+    [0.3280, 0.0047, 0.6410], [0.3526, 0.0028, 0.6479], [0.3763, 0.0008, 0.6530],
+    [0.3996, 0.0000, 0.6566], [0.4227, 0.0000, 0.6585], [0.4458, 0.0027, 0.6589],
+    [0.4692, 0.0090, 0.6579], [0.4926, 0.0176, 0.6555], [0.5160, 0.0283, 0.6518],
+    [0.5390, 0.0407, 0.6466], [0.5617, 0.0545, 0.6401], [0.5837, 0.0693, 0.6322],
+    [0.6049, 0.0849, 0.6230], [0.6252, 0.1010, 0.6125], [0.6448, 0.1177, 0.6008],
+    [0.6636, 0.1350, 0.5883], [0.6819, 0.1528, 0.5749], [0.6996, 0.1709, 0.5610],
+    [0.7169, 0.1892, 0.5466], [0.7338, 0.2075, 0.5320], [0.7503, 0.2258, 0.5173],
+    [0.7664, 0.2437, 0.5023], [0.7820, 0.2613, 0.4871], [0.7971, 0.2787, 0.4720],
+    [0.8117, 0.2961, 0.4570], [0.8259, 0.3135, 0.4421], [0.8396, 0.3312, 0.4276],
+    [0.8529, 0.3493, 0.4133], [0.8658, 0.3677, 0.3996], [0.8783, 0.3863, 0.3862],
+    [0.8903, 0.4051, 0.3730], [0.9017, 0.4240, 0.3601], [0.9126, 0.4429, 0.3472],
+    [0.9229, 0.4619, 0.3342], [0.9325, 0.4809, 0.3211], [0.9415, 0.5001, 0.3079],
+    [0.9498, 0.5198, 0.2946], [0.9574, 0.5399, 0.2813], [0.9644, 0.5604, 0.2680],
+    [0.9707, 0.5814, 0.2547], [0.9764, 0.6027, 0.2415], [0.9814, 0.6245, 0.2285],
+    [0.9857, 0.6464, 0.2158], [0.9890, 0.6686, 0.2035], [0.9914, 0.6914, 0.1916],
+    [0.9929, 0.7149, 0.1800], [0.9935, 0.7392, 0.1688], [0.9932, 0.7647, 0.1581],
+    [0.9920, 0.7912, 0.1479], [0.9897, 0.8188, 0.1386], [0.9861, 0.8469, 0.1304],
+    [0.9812, 0.8752, 0.1241], [0.9745, 0.9029, 0.1201], [0.9658, 0.9295, 0.1192],
+    [0.9548, 0.9543, 0.1220], [0.9412, 0.9765, 0.1294],
+];
+
+#[rustfmt::skip]
+const CIVIDIS: [[f32; 3]; 65] = [
+    [0.0000, 0.1255, 0.3020], [0.0000, 0.1359, 0.3220], [0.0000, 0.1462, 0.3437],
+    [0.0000, 0.1565, 0.3659], [0.0000, 0.1669, 0.3873], [0.0000, 0.1773, 0.4066],
+    [0.0000, 0.1879, 0.4227], [0.0000, 0.1988, 0.4343], [0.0180, 0.2099, 0.4403],
+    [0.0461, 0.2214, 0.4412], [0.0800, 0.2331, 0.4384], [0.1169, 0.2449, 0.4335],
+    [0.1540, 0.2566, 0.4278], [0.1886, 0.2683, 0.4227], [0.2180, 0.2798, 0.4198],
+    [0.2411, 0.2911, 0.4194], [0.2608, 0.3021, 0.4195], [0.2781, 0.3131, 0.4202],
+    [0.2935, 0.3240, 0.4214], [0.3079, 0.3349, 0.4229], [0.3218, 0.3459, 0.4247],
+    [0.3362, 0.3570, 0.4267], [0.3511, 0.3683, 0.4291], [0.3654, 0.3797, 0.4319],
+    [0.3792, 0.3912, 0.4351], [0.3927, 0.4028, 0.4386], [0.4060, 0.4144, 0.4423],
+    [0.4194, 0.4261, 0.4459], [0.4330, 0.4379, 0.4495], [0.4470, 0.4497, 0.4529],
+    [0.4611, 0.4617, 0.4569], [0.4752, 0.4739, 0.4611], [0.4895, 0.4860, 0.4652],
+    [0.5038, 0.4982, 0.4689], [0.5183, 0.5105, 0.4720], [0.5329, 0.5227, 0.4740],
+    [0.5478, 0.5348, 0.4746], [0.5628, 0.5468, 0.4742], [0.5780, 0.5588, 0.4728],
+    [0.5934, 0.5707, 0.4706], [0.6089, 0.5828, 0.4679], [0.6245, 0.5949, 0.4647],
+    [0.6404, 0.6073, 0.4612], [0.6563, 0.6199, 0.4576], [0.6724, 0.6327, 0.4539],
+    [0.6887, 0.6457, 0.4498], [0.7052, 0.6588, 0.4454], [0.7217, 0.6721, 0.4405],
+    [0.7385, 0.6855, 0.4350], [0.7554, 0.6991, 0.4289], [0.7724, 0.7129, 0.4219],
+    [0.7900, 0.7268, 0.4141], [0.8079, 0.7410, 0.4055], [0.8261, 0.7553, 0.3963],
+    [0.8442, 0.7697, 0.3866], [0.8620, 0.7842, 0.3765], [0.8793, 0.7988, 0.3662],
+    [0.8959, 0.8134, 0.3557], [0.9118, 0.8281, 0.3449], [0.9270, 0.8429, 0.3336],
+    [0.9418, 0.8578, 0.3219], [0.9563, 0.8728, 0.3101], [0.9708, 0.8878, 0.2981],
+    [0.9853, 0.9027, 0.2862], [1.0000, 0.9176, 0.2745],
+];
+
+#[rustfmt::skip]
+const TURBO: [[f32; 3]; 65] = [
+    [0.1882, 0.0706, 0.2314], [0.2412, 0.1104, 0.3863], [0.2749, 0.1529, 0.5109],
+    [0.2931, 0.1971, 0.6098], [0.2991, 0.2426, 0.6876], [0.2963, 0.2886, 0.7488],
+    [0.2876, 0.3344, 0.7978], [0.2759, 0.3794, 0.8389], [0.2649, 0.4229, 0.8764],
+    [0.2568, 0.4647, 0.9113], [0.2504, 0.5051, 0.9418], [0.2445, 0.5444, 0.9659],
+    [0.2378, 0.5829, 0.9818], [0.2286, 0.6207, 0.9872], [0.2154, 0.6583, 0.9800],
+    [0.1961, 0.6956, 0.9603], [0.1712, 0.7318, 0.9331], [0.1446, 0.7665, 0.8998],
+    [0.1203, 0.7996, 0.8617], [0.1018, 0.8306, 0.8199], [0.0929, 0.8595, 0.7754],
+    [0.0971, 0.8859, 0.7293], [0.1171, 0.9097, 0.6816], [0.1514, 0.9310, 0.6308],
+    [0.1966, 0.9496, 0.5784], [0.2498, 0.9654, 0.5261], [0.3075, 0.9782, 0.4756],
+    [0.3665, 0.9879, 0.4282], [0.4233, 0.9943, 0.3856], [0.4757, 0.9974, 0.3488],
+    [0.5260, 0.9972, 0.3159], [0.5749, 0.9937, 0.2868], [0.6224, 0.9870, 0.2616],
+    [0.6685, 0.9771, 0.2404], [0.7133, 0.9639, 0.2231], [0.7568, 0.9476, 0.2097],
+    [0.7987, 0.9283, 0.2007], [0.8370, 0.9061, 0.1989], [0.8712, 0.8811, 0.2027],
+    [0.9015, 0.8534, 0.2098], [0.9279, 0.8230, 0.2177], [0.9505, 0.7899, 0.2239],
+    [0.9696, 0.7544, 0.2258], [0.9803, 0.7165, 0.2211], [0.9766, 0.6759, 0.2095],
+    [0.9731, 0.6330, 0.1928], [0.9702, 0.5887, 0.1727], [0.9683, 0.5435, 0.1510],
+    [0.9679, 0.4982, 0.1296], [0.9694, 0.4535, 0.1104], [0.9612, 0.4102, 0.0950],
+    [0.9462, 0.3695, 0.0816], [0.9311, 0.3313, 0.0689], [0.9138, 0.2952, 0.0568],
+    [0.8928, 0.2605, 0.0453], [0.8662, 0.2269, 0.0345], [0.8328, 0.1939, 0.0242],
+    [0.7910, 0.1611, 0.0147], [0.7417, 0.1287, 0.0065], [0.6882, 0.0982, 0.0000],
+    [0.6337, 0.0706, 0.0000], [0.5817, 0.0473, 0.0000], [0.5359, 0.0295, 0.0006],
+    [0.5002, 0.0186, 0.0065], [0.4784, 0.0157, 0.0118],
+];
+
+#[rustfmt::skip]
+const COOLWARM: [[f32; 3]; 65] = [
+    [0.2314, 0.2980, 0.7529], [0.2494, 0.3254, 0.7774], [0.2677, 0.3525, 0.8008],
+    [0.2862, 0.3793, 0.8231], [0.3051, 0.4060, 0.8444], [0.3243, 0.4323, 0.8644],
+    [0.3439, 0.4582, 0.8832], [0.3638, 0.4838, 0.9007], [0.3840, 0.5089, 0.9169],
+    [0.4044, 0.5336, 0.9318], [0.4252, 0.5577, 0.9452], [0.4462, 0.5813, 0.9573],
+    [0.4673, 0.6042, 0.9678], [0.4887, 0.6264, 0.9769], [0.5102, 0.6480, 0.9845],
+    [0.5318, 0.6687, 0.9906], [0.5534, 0.6886, 0.9952], [0.5751, 0.7077, 0.9982],
+    [0.5966, 0.7259, 0.9997], [0.6181, 0.7432, 0.9996], [0.6395, 0.7594, 0.9980],
+    [0.6606, 0.7746, 0.9949], [0.6815, 0.7888, 0.9903], [0.7021, 0.8019, 0.9841],
+    [0.7224, 0.8138, 0.9765], [0.7422, 0.8246, 0.9674], [0.7616, 0.8342, 0.9569],
+    [0.7804, 0.8426, 0.9449], [0.7988, 0.8497, 0.9316], [0.8165, 0.8556, 0.9170],
+    [0.8335, 0.8602, 0.9010], [0.8498, 0.8635, 0.8838], [0.8654, 0.8654, 0.8653],
+    [0.8824, 0.8578, 0.8434], [0.8979, 0.8490, 0.8209], [0.9118, 0.8389, 0.7979],
+    [0.9243, 0.8275, 0.7745], [0.9353, 0.8149, 0.7508], [0.9447, 0.8011, 0.7268],
+    [0.9527, 0.7861, 0.7025], [0.9592, 0.7699, 0.6781], [0.9641, 0.7527, 0.6535],
+    [0.9677, 0.7343, 0.6288], [0.9697, 0.7149, 0.6041], [0.9703, 0.6945, 0.5794],
+    [0.9695, 0.6731, 0.5548], [0.9672, 0.6507, 0.5302], [0.9635, 0.6274, 0.5058],
+    [0.9584, 0.6031, 0.4817], [0.9519, 0.5780, 0.4577], [0.9441, 0.5520, 0.4340],
+    [0.9349, 0.5252, 0.4107], [0.9244, 0.4976, 0.3876], [0.9125, 0.4691, 0.3650],
+    [0.8994, 0.4398, 0.3428], [0.8851, 0.4097, 0.3210], [0.8696, 0.3786, 0.2997],
+    [0.8528, 0.3465, 0.2788], [0.8350, 0.3131, 0.2585], [0.8160, 0.2783, 0.2388],
+    [0.7959, 0.2415, 0.2196], [0.7749, 0.2019, 0.2010], [0.7528, 0.1575, 0.1831],
+    [0.7298, 0.1034, 0.1657], [0.7059, 0.0158, 0.1490],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn end_points() {
+        let to_u8 = |color: Color| {
+            let ColorComponents(r, g, b) = color.components().clone();
+            [r, g, b].map(|v| (v * 255.0).round() as u8)
+        };
+
+        assert_eq!(
+            to_u8(Colormap::Viridis.sample(0.0, ColorSpace::Srgb)),
+            [68, 1, 84]
+        );
+        assert_eq!(
+            to_u8(Colormap::Viridis.sample(1.0, ColorSpace::Srgb)),
+            [253, 231, 37]
+        );
+        assert_eq!(
+            to_u8(Colormap::Coolwarm.sample(0.5, ColorSpace::Srgb)),
+            [221, 221, 221]
+        );
+    }
+
+    #[test]
+    fn perceptual_maps_are_more_uniform_than_turbo() {
+        let viridis = Colormap::Viridis.uniformity(64, ColorSpace::Oklab);
+        let turbo = Colormap::Turbo.uniformity(64, ColorSpace::Oklab);
+
+        assert_eq!(viridis.steps.len(), 63);
+        assert!(viridis.coefficient_of_variation() < turbo.coefficient_of_variation());
+    }
+}
@@ -1,18 +1,25 @@
 #![allow(clippy::excessive_precision)]
 
-use crate::ColorSpace;
+use crate::cam16;
+use crate::hct;
+use crate::jzazbz;
+use crate::{ChromaticAdaptation, ColorSpace, WhitePoint};
 
 use super::ColorComponents;
 use std::f32::consts::PI;
 
-type Transform = euclid::Transform3D<f32, (), ()>;
+pub(crate) type Transform = euclid::Transform3D<f32, (), ()>;
 type Vector = euclid::Vector3D<f32, ()>;
 
 const RAD_PER_DEG: f32 = PI / 180.0;
 const DEG_PER_RAD: f32 = 180.0 / PI;
 
+/// The luminance in cd/m² of media white when placing relative colors in
+/// spaces with absolute luminance, as recommended by ITU-R BT.2408.
+pub(crate) const REFERENCE_WHITE: f32 = 203.0;
+
 #[inline]
-fn transform(from: &ColorComponents, mat: &Transform) -> ColorComponents {
+pub(crate) fn transform(from: &ColorComponents, mat: &Transform) -> ColorComponents {
     unsafe {
         std::mem::transmute::<Vector, ColorComponents>(
             mat.transform_vector3d(Vector::new(from.0, from.1, from.2)),
@@ -20,42 +27,15 @@ fn transform(from: &ColorComponents, mat: &Transform) -> ColorComponents {
     }
 }
 
-fn xyz_d65_to_xyz_d50(from: &ColorComponents) -> ColorComponents {
-    #[rustfmt::skip]
-    const MAT: Transform = Transform::new(
-         1.0479298208405488,    0.029627815688159344, -0.009243058152591178, 0.0,
-         0.022946793341019088,  0.990434484573249,     0.015055144896577895, 0.0,
-        -0.05019222954313557,  -0.01707382502938514,   0.7518742899580008,   0.0,
-         0.0,                   0.0,                   0.0,                  1.0,
-    );
-
-    transform(from, &MAT)
-}
-
-fn xyz_d50_to_xyz_d65(from: &ColorComponents) -> ColorComponents {
-    #[rustfmt::skip]
-    const MAT: Transform = Transform::new(
-         0.9554734527042182,   -0.028369706963208136,  0.012314001688319899, 0.0,
-        -0.023098536874261423,  1.0099954580058226,   -0.020507696433477912, 0.0,
-         0.0632593086610217,    0.021041398966943008,  1.3303659366080753,   0.0,
-         0.0,                   0.0,                   0.0,                  1.0,
-    );
-
-    transform(from, &MAT)
-}
-
-enum WhitePoint {
-    D50,
-    D65,
-}
-
-fn convert_white_point(from: WhitePoint, to: WhitePoint, components: &mut ColorComponents) {
-    match (from, to) {
-        (WhitePoint::D50, WhitePoint::D65) => *components = xyz_d50_to_xyz_d65(components),
-        (WhitePoint::D65, WhitePoint::D50) => *components = xyz_d65_to_xyz_d50(components),
-
-        // Don't touch the components if they have the same white point.
-        _ => {}
+fn convert_white_point(
+    from: WhitePoint,
+    to: WhitePoint,
+    adaptation: ChromaticAdaptation,
+    components: &mut ColorComponents,
+) {
+    // Don't touch the components if they have the same white point.
+    if from != to {
+        *components = adaptation.adapt(from, to, components);
     }
 }
 
@@ -84,11 +64,12 @@ fn to_xyz<From: ColorSpaceConversion>(from: &ColorComponents) -> (ColorComponent
 fn from_xyz<To: ColorSpaceConversion>(
     from: &ColorComponents,
     white_point: WhitePoint,
+    adaptation: ChromaticAdaptation,
 ) -> ColorComponents {
     let mut xyz = from.clone();
 
     // Convert the white point if needed.
-    convert_white_point(white_point, To::WHITE_POINT, &mut xyz);
+    convert_white_point(white_point, To::WHITE_POINT, adaptation, &mut xyz);
 
     // Convert the color from XYZ to the target color space.
     let result = To::from_xyz(&xyz);
@@ -104,21 +85,71 @@ pub fn convert(
     from_color_space: ColorSpace,
     from_components: &ColorComponents,
     to_color_space: ColorSpace,
+) -> ColorComponents {
+    convert_with_adaptation(
+        from_color_space,
+        from_components,
+        to_color_space,
+        ChromaticAdaptation::Bradford,
+    )
+}
+
+/// Convert the given color components from the given color space to the target
+/// color space, using `adaptation` when the white points differ.
+pub fn convert_with_adaptation(
+    from_color_space: ColorSpace,
+    from_components: &ColorComponents,
+    to_color_space: ColorSpace,
+    adaptation: ChromaticAdaptation,
 ) -> ColorComponents {
     if from_color_space == to_color_space {
         return from_components.clone();
     }
 
-    // TODO: If there a nicer way to do this conversion from enum value to type
-    // to about these matches?
-
     // Convert from the source color space to XYZ (with the white point
     // specified for the source color space).
-    let (xyz, white_point) = match from_color_space {
+    let (xyz, white_point) = color_space_to_xyz(from_color_space, from_components);
+
+    // Convert from XYZ (with from color space white point) to the target color
+    // space.
+    color_space_from_xyz(to_color_space, &xyz, white_point, adaptation)
+}
+
+/// Convert the color components from the given color space to XYZ and return
+/// the components and the white point of the color space.
+pub(crate) fn color_space_to_xyz(
+    from_color_space: ColorSpace,
+    from_components: &ColorComponents,
+) -> (ColorComponents, WhitePoint) {
+    // TODO: If there a nicer way to do this conversion from enum value to type
+    // to about these matches?
+    match from_color_space {
         ColorSpace::Lab => to_xyz::<Lab>(from_components),
         ColorSpace::Lch => to_xyz::<Lch>(from_components),
         ColorSpace::Oklab => to_xyz::<Oklab>(from_components),
         ColorSpace::Oklch => to_xyz::<Oklch>(from_components),
+        ColorSpace::Luv => to_xyz::<Luv>(from_components),
+        ColorSpace::LchUv => to_xyz::<LchUv>(from_components),
+        ColorSpace::LuvD50 => to_xyz::<LuvD50>(from_components),
+        ColorSpace::LchUvD50 => to_xyz::<LchUvD50>(from_components),
+        ColorSpace::Hsluv => to_xyz::<Hsluv>(from_components),
+        ColorSpace::Hpluv => to_xyz::<Hpluv>(from_components),
+        ColorSpace::Okhsl => to_xyz::<Okhsl>(from_components),
+        ColorSpace::Okhsv => to_xyz::<Okhsv>(from_components),
+        ColorSpace::Hsv => to_xyz::<Hsv>(from_components),
+        ColorSpace::Cam16Ucs => to_xyz::<Cam16Ucs>(from_components),
+        ColorSpace::Hct => to_xyz::<Hct>(from_components),
+        ColorSpace::Jzazbz => to_xyz::<Jzazbz>(from_components),
+        ColorSpace::Jzczhz => to_xyz::<Jzczhz>(from_components),
+        ColorSpace::Ictcp => to_xyz::<Ictcp>(from_components),
+        ColorSpace::IctcpHlg => to_xyz::<IctcpHlg>(from_components),
+        ColorSpace::Rec2100Pq => to_xyz::<Rec2100Pq>(from_components),
+        ColorSpace::Rec2100Hlg => to_xyz::<Rec2100Hlg>(from_components),
+        ColorSpace::Rec2100Linear => to_xyz::<Rec2100Linear>(from_components),
+        ColorSpace::Aces2065_1 => to_xyz::<Aces2065_1>(from_components),
+        ColorSpace::Acescg => to_xyz::<Acescg>(from_components),
+        ColorSpace::Acescc => to_xyz::<Acescc>(from_components),
+        ColorSpace::Acescct => to_xyz::<Acescct>(from_components),
         ColorSpace::Srgb => to_xyz::<Srgb>(from_components),
         ColorSpace::SrgbLinear => to_xyz::<SrgbLinear>(from_components),
         ColorSpace::DisplayP3 => to_xyz::<DisplayP3>(from_components),
@@ -127,23 +158,62 @@ pub fn convert(
         ColorSpace::Rec2020 => to_xyz::<Rec2020>(from_components),
         ColorSpace::XyzD50 => to_xyz::<XyzD50>(from_components),
         ColorSpace::XyzD65 => to_xyz::<XyzD65>(from_components),
-    };
+        ColorSpace::Custom(space) => {
+            let space = space.rgb_color_space();
+            (space.to_xyz(from_components), space.white_point())
+        }
+    }
+}
 
-    // Convert from XYZ (with from color space white point) to the target color
-    // space.
+/// Convert XYZ components at the given white point to the target color space,
+/// using `adaptation` when the white points differ.
+pub(crate) fn color_space_from_xyz(
+    to_color_space: ColorSpace,
+    xyz: &ColorComponents,
+    white_point: WhitePoint,
+    adaptation: ChromaticAdaptation,
+) -> ColorComponents {
     match to_color_space {
-        ColorSpace::Lab => from_xyz::<Lab>(&xyz, white_point),
-        ColorSpace::Lch => from_xyz::<Lch>(&xyz, white_point),
-        ColorSpace::Oklab => from_xyz::<Oklab>(&xyz, white_point),
-        ColorSpace::Oklch => from_xyz::<Oklch>(&xyz, white_point),
-        ColorSpace::Srgb => from_xyz::<Srgb>(&xyz, white_point),
-        ColorSpace::SrgbLinear => from_xyz::<SrgbLinear>(&xyz, white_point),
-        ColorSpace::DisplayP3 => from_xyz::<DisplayP3>(&xyz, white_point),
-        ColorSpace::A98Rgb => from_xyz::<A98Rgb>(&xyz, white_point),
-        ColorSpace::ProphotoRgb => from_xyz::<ProphotoRgb>(&xyz, white_point),
-        ColorSpace::Rec2020 => from_xyz::<Rec2020>(&xyz, white_point),
-        ColorSpace::XyzD50 => from_xyz::<XyzD50>(&xyz, white_point),
-        ColorSpace::XyzD65 => from_xyz::<XyzD65>(&xyz, white_point),
+        ColorSpace::Lab => from_xyz::<Lab>(xyz, white_point, adaptation),
+        ColorSpace::Lch => from_xyz::<Lch>(xyz, white_point, adaptation),
+        ColorSpace::Oklab => from_xyz::<Oklab>(xyz, white_point, adaptation),
+        ColorSpace::Oklch => from_xyz::<Oklch>(xyz, white_point, adaptation),
+        ColorSpace::Luv => from_xyz::<Luv>(xyz, white_point, adaptation),
+        ColorSpace::LchUv => from_xyz::<LchUv>(xyz, white_point, adaptation),
+        ColorSpace::LuvD50 => from_xyz::<LuvD50>(xyz, white_point, adaptation),
+        ColorSpace::LchUvD50 => from_xyz::<LchUvD50>(xyz, white_point, adaptation),
+        ColorSpace::Hsluv => from_xyz::<Hsluv>(xyz, white_point, adaptation),
+        ColorSpace::Hpluv => from_xyz::<Hpluv>(xyz, white_point, adaptation),
+        ColorSpace::Okhsl => from_xyz::<Okhsl>(xyz, white_point, adaptation),
+        ColorSpace::Okhsv => from_xyz::<Okhsv>(xyz, white_point, adaptation),
+        ColorSpace::Hsv => from_xyz::<Hsv>(xyz, white_point, adaptation),
+        ColorSpace::Cam16Ucs => from_xyz::<Cam16Ucs>(xyz, white_point, adaptation),
+        ColorSpace::Hct => from_xyz::<Hct>(xyz, white_point, adaptation),
+        ColorSpace::Jzazbz => from_xyz::<Jzazbz>(xyz, white_point, adaptation),
+        ColorSpace::Jzczhz => from_xyz::<Jzczhz>(xyz, white_point, adaptation),
+        ColorSpace::Ictcp => from_xyz::<Ictcp>(xyz, white_point, adaptation),
+        ColorSpace::IctcpHlg => from_xyz::<IctcpHlg>(xyz, white_point, adaptation),
+        ColorSpace::Rec2100Pq => from_xyz::<Rec2100Pq>(xyz, white_point, adaptation),
+        ColorSpace::Rec2100Hlg => from_xyz::<Rec2100Hlg>(xyz, white_point, adaptation),
+        ColorSpace::Rec2100Linear => from_xyz::<Rec2100Linear>(xyz, white_point, adaptation),
+        ColorSpace::Aces2065_1 => from_xyz::<Aces2065_1>(xyz, white_point, adaptation),
+        ColorSpace::Acescg => from_xyz::<Acescg>(xyz, white_point, adaptation),
+        ColorSpace::Acescc => from_xyz::<Acescc>(xyz, white_point, adaptation),
+        ColorSpace::Acescct => from_xyz::<Acescct>(xyz, white_point, adaptation),
+        ColorSpace::Srgb => from_xyz::<Srgb>(xyz, white_point, adaptation),
+        ColorSpace::SrgbLinear => from_xyz::<SrgbLinear>(xyz, white_point, adaptation),
+        ColorSpace::DisplayP3 => from_xyz::<DisplayP3>(xyz, white_point, adaptation),
+        ColorSpace::A98Rgb => from_xyz::<A98Rgb>(xyz, white_point, adaptation),
+        ColorSpace::ProphotoRgb => from_xyz::<ProphotoRgb>(xyz, white_point, adaptation),
+        ColorSpace::Rec2020 => from_xyz::<Rec2020>(xyz, white_point, adaptation),
+        ColorSpace::XyzD50 => from_xyz::<XyzD50>(xyz, white_point, adaptation),
+        ColorSpace::XyzD65 => from_xyz::<XyzD65>(xyz, white_point, adaptation),
+        ColorSpace::Custom(space) => {
+            let space = space.rgb_color_space();
+            let mut xyz = xyz.clone();
+            convert_white_point(white_point, space.white_point(), adaptation, &mut xyz);
+            space.components_from_xyz(&xyz)
+        }
     }
 }
 
@@ -203,6 +273,68 @@ impl ColorSpaceConversion for Srgb {
     }
 }
 
+struct Hsv;
+
+impl Hsv {
+    /// Convert HSV to sRGB as specified in [1].
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_RGB_alternative
+    fn to_srgb(from: &ColorComponents) -> ColorComponents {
+        let ColorComponents(hue, saturation, value) = *from;
+        let hue = hue.rem_euclid(360.0) / 60.0;
+
+        let f = |n: f32| {
+            let k = (n + hue) % 6.0;
+            value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+
+        ColorComponents(f(5.0), f(3.0), f(1.0))
+    }
+
+    /// Convert sRGB to HSV. The hue is powerless when the saturation is 0
+    /// and is set to 0.
+    fn from_srgb(from: &ColorComponents) -> ColorComponents {
+        let ColorComponents(red, green, blue) = *from;
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == red {
+            (green - blue) / delta + if green < blue { 6.0 } else { 0.0 }
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        } * 60.0;
+
+        ColorComponents(hue, saturation, max)
+    }
+}
+
+impl ColorSpaceConversion for Hsv {
+    const WHITE_POINT: WhitePoint = Srgb::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        Srgb::to_linear_light(&Self::to_srgb(from))
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        Srgb::to_xyz(from)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Srgb::from_xyz(from)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        Self::from_srgb(&Srgb::to_gamma_corrected(from))
+    }
+}
+
 struct SrgbLinear;
 
 impl ColorSpaceConversion for SrgbLinear {
@@ -237,6 +369,14 @@ impl DisplayP3 {
         0.1982172852343625,  0.079286914093745,   1.0439443689009757,   0.0,
         0.0,                 0.0,                 0.0,                  1.0,
     );
+
+    #[rustfmt::skip]
+    const FROM_XYZ: Transform = Transform::new(
+         2.493496911941425,   -0.8294889695615749,  0.035845830243784335, 0.0,
+        -0.9313836179191236,   1.7626640603183468, -0.07617238926804171,  0.0,
+        -0.40271078445071684,  0.023624685841943598, 0.9568845240076874,  0.0,
+         0.0,                  0.0,                  0.0,                 1.0,
+    );
 }
 
 impl ColorSpaceConversion for DisplayP3 {
@@ -250,12 +390,12 @@ impl ColorSpaceConversion for DisplayP3 {
         transform(from, &Self::TO_XYZ)
     }
 
-    fn from_xyz(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        transform(from, &Self::FROM_XYZ)
     }
 
-    fn to_gamma_corrected(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        Srgb::to_gamma_corrected(from)
     }
 }
 
@@ -269,6 +409,14 @@ impl A98Rgb {
         0.18822864623499472, 0.07529145849399789, 0.9913375368376389,   0.0,
         0.0,                 0.0,                 0.0,                  1.0,
     );
+
+    #[rustfmt::skip]
+    const FROM_XYZ: Transform = Transform::new(
+         2.041587903810746,   -0.9692436362808797,  0.013444280632031015, 0.0,
+        -0.5650069742788595,   1.8759675015077206, -0.11836239223101822,  0.0,
+        -0.34473135077832945,  0.04155505740717559, 1.0151749943912054,   0.0,
+         0.0,                  0.0,                 0.0,                  1.0,
+    );
 }
 
 impl ColorSpaceConversion for A98Rgb {
@@ -287,12 +435,12 @@ impl ColorSpaceConversion for A98Rgb {
         transform(from, &Self::TO_XYZ)
     }
 
-    fn from_xyz(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        transform(from, &Self::FROM_XYZ)
     }
 
-    fn to_gamma_corrected(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| value.signum() * value.abs().powf(256.0 / 563.0))
     }
 }
 
@@ -306,6 +454,14 @@ impl ProphotoRgb {
         0.0313493495815248,  0.00008565396060525902, 0.8251046025104601, 0.0,
         0.0,                 0.0,                    0.0,                1.0,
     );
+
+    #[rustfmt::skip]
+    const FROM_XYZ: Transform = Transform::new(
+         1.345798973102828,  -0.5446224939028347,  0.0,                0.0,
+        -0.2555801000799754,  1.5082327413132781,  0.0,                0.0,
+        -0.051106285067534,   0.020536032391479726, 1.2119675456389454, 0.0,
+         0.0,                 0.0,                  0.0,                1.0,
+    );
 }
 
 impl ColorSpaceConversion for ProphotoRgb {
@@ -329,12 +485,22 @@ impl ColorSpaceConversion for ProphotoRgb {
         transform(from, &Self::TO_XYZ)
     }
 
-    fn from_xyz(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        transform(from, &Self::FROM_XYZ)
     }
 
-    fn to_gamma_corrected(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| {
+            const ET: f32 = 1.0 / 512.0;
+
+            let abs = value.abs();
+
+            if abs >= ET {
+                value.signum() * abs.powf(1.0 / 1.8)
+            } else {
+                16.0 * value
+            }
+        })
     }
 }
 
@@ -348,6 +514,22 @@ impl Rec2020 {
         0.16888097516417205, 0.059301716469861945, 1.0609850577107909,   0.0,
         0.0,                 0.0,                  0.0,                  1.0,
     );
+
+    #[rustfmt::skip]
+    const FROM_XYZ: Transform = Transform::new(
+         1.7166511879712676, -0.666684351832489,   0.01763985744531091,  0.0,
+        -0.3556707837763924,  1.616481236634939,  -0.042770613257808655, 0.0,
+        -0.2533662813736598,  0.01576854581391112, 0.942103121235474,    0.0,
+         0.0,                 0.0,                 0.0,                  1.0,
+    );
+
+    const ALPHA: f32 = 1.09929682680944;
+    const BETA: f32 = 0.018053968510807;
+
+    /// The relative luminance of linear light components.
+    fn luminance(from: &ColorComponents) -> f32 {
+        0.2627 * from.0 + 0.6780 * from.1 + 0.0593 * from.2
+    }
 }
 
 impl ColorSpaceConversion for Rec2020 {
@@ -355,15 +537,12 @@ impl ColorSpaceConversion for Rec2020 {
 
     fn to_linear_light(from: &ColorComponents) -> ColorComponents {
         from.copy_and_apply(|value| {
-            const ALPHA: f32 = 1.09929682680944;
-            const BETA: f32 = 0.018053968510807;
-
             let abs = value.abs();
 
-            if abs < BETA * 4.5 {
+            if abs < Self::BETA * 4.5 {
                 value / 4.5
             } else {
-                value.signum() * ((abs + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+                value.signum() * ((abs + Self::ALPHA - 1.0) / Self::ALPHA).powf(1.0 / 0.45)
             }
         })
     }
@@ -372,215 +551,1081 @@ impl ColorSpaceConversion for Rec2020 {
         transform(from, &Self::TO_XYZ)
     }
 
-    fn from_xyz(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        transform(from, &Self::FROM_XYZ)
     }
 
-    fn to_gamma_corrected(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| {
+            let abs = value.abs();
+
+            if abs > Self::BETA {
+                value.signum() * (Self::ALPHA * abs.powf(0.45) - (Self::ALPHA - 1.0))
+            } else {
+                4.5 * value
+            }
+        })
     }
 }
 
-struct XyzD50;
+struct Rec2100Pq;
 
-impl ColorSpaceConversion for XyzD50 {
-    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+impl ColorSpaceConversion for Rec2100Pq {
+    const WHITE_POINT: WhitePoint = Rec2020::WHITE_POINT;
 
     fn to_linear_light(from: &ColorComponents) -> ColorComponents {
-        from.clone()
+        from.copy_and_apply(|value| Pq::decode(value) * Pq::MAX_LUMINANCE / REFERENCE_WHITE)
     }
 
     fn to_xyz(from: &ColorComponents) -> ColorComponents {
-        from.clone()
+        Rec2020::to_xyz(from)
     }
 
-    fn from_xyz(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Rec2020::from_xyz(from)
     }
 
-    fn to_gamma_corrected(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| Pq::encode(value * REFERENCE_WHITE / Pq::MAX_LUMINANCE))
     }
 }
 
-struct XyzD65;
+struct Rec2100Hlg;
 
-impl ColorSpaceConversion for XyzD65 {
-    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+impl ColorSpaceConversion for Rec2100Hlg {
+    const WHITE_POINT: WhitePoint = Rec2020::WHITE_POINT;
 
     fn to_linear_light(from: &ColorComponents) -> ColorComponents {
-        from.clone()
+        hlg_to_linear(from, Hlg::SYSTEM_GAMMA)
     }
 
     fn to_xyz(from: &ColorComponents) -> ColorComponents {
-        from.clone()
+        Rec2020::to_xyz(from)
     }
 
-    fn from_xyz(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Rec2020::from_xyz(from)
     }
 
-    fn to_gamma_corrected(_from: &ColorComponents) -> ColorComponents {
-        todo!()
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        linear_to_hlg(from, Hlg::SYSTEM_GAMMA)
     }
 }
 
-struct Lab;
-
-impl Lab {
-    const KAPPA: f32 = 24389.0 / 27.0;
-    const EPSILON: f32 = 216.0 / 24389.0;
-    const WHITE: ColorComponents = ColorComponents(0.96422, 1.0, 0.82521);
-}
+struct Rec2100Linear;
 
-impl ColorSpaceConversion for Lab {
-    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+impl ColorSpaceConversion for Rec2100Linear {
+    const WHITE_POINT: WhitePoint = Rec2020::WHITE_POINT;
 
     fn to_linear_light(from: &ColorComponents) -> ColorComponents {
-        // No need for conversion.
+        // Already in linear light form.
         from.clone()
     }
 
-    /// Convert a CIELAB color to XYZ as specified in [1] and [2].
-    ///
-    /// [1]: https://drafts.csswg.org/css-color/#lab-to-predefined
-    /// [2]: https://drafts.csswg.org/css-color/#color-conversion-code
     fn to_xyz(from: &ColorComponents) -> ColorComponents {
-        let f1 = (from.0 + 16.0) / 116.0;
-        let f0 = (from.1 / 500.0) + f1;
-        let f2 = f1 - from.2 / 200.0;
-
-        let x = if f0.powf(3.0) > Self::EPSILON {
-            f0.powf(3.)
-        } else {
-            (116.0 * f0 - 16.0) / Self::KAPPA
-        };
-        let y = if from.0 > Self::KAPPA * Self::EPSILON {
-            ((from.0 + 16.0) / 116.0).powf(3.0)
-        } else {
-            from.0 / Self::KAPPA
-        };
-        let z = if f2.powf(3.0) > Self::EPSILON {
-            f2.powf(3.0)
-        } else {
-            (116.0 * f2 - 16.0) / Self::KAPPA
-        };
-
-        ColorComponents(x * Self::WHITE.0, y * Self::WHITE.1, z * Self::WHITE.2)
+        Rec2020::to_xyz(from)
     }
 
-    /// Convert an XYZ colour to LAB as specified in [1] and [2].
-    ///
-    /// [1]: https://drafts.csswg.org/css-color/#rgb-to-lab
-    /// [2]: https://drafts.csswg.org/css-color/#color-conversion-code
     fn from_xyz(from: &ColorComponents) -> ColorComponents {
-        macro_rules! compute_f {
-            ($value:expr) => {{
-                if $value > Self::EPSILON {
-                    $value.cbrt()
-                } else {
-                    (Self::KAPPA * $value + 16.0) / 116.0
-                }
-            }};
-        }
-
-        // 4. Convert D50-adapted XYZ to Lab.
-        let f = [
-            compute_f!(from.0 / Self::WHITE.0),
-            compute_f!(from.1 / Self::WHITE.1),
-            compute_f!(from.2 / Self::WHITE.2),
-        ];
-
-        let lightness = 116.0 * f[1] - 16.0;
-        let a = 500.0 * (f[0] - f[1]);
-        let b = 200.0 * (f[1] - f[2]);
-
-        ColorComponents(lightness, a, b)
+        Rec2020::from_xyz(from)
     }
 
     fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
-        // No need for conversion.
+        // Stay in linear light form.
         from.clone()
     }
 }
 
-struct Lch;
+struct Aces2065_1;
 
-impl ColorSpaceConversion for Lch {
-    const WHITE_POINT: WhitePoint = Lab::WHITE_POINT;
+impl Aces2065_1 {
+    #[rustfmt::skip]
+    const TO_XYZ: Transform = Transform::new(
+        0.9525523959381859,    0.3439664497650751,  0.0,                0.0,
+        0.0,                   0.7281660966134856,  0.0,                0.0,
+        0.00009367863166046855, -0.07213254637856079, 1.0088251843515859, 0.0,
+        0.0,                   0.0,                 0.0,                1.0,
+    );
+
+    #[rustfmt::skip]
+    const FROM_XYZ: Transform = Transform::new(
+         1.0498110174979742,    -0.4959030230773199,  0.0,               0.0,
+         0.0,                    1.3733130458157066,  0.0,               0.0,
+        -0.00009748454057925287, 0.09824003605730999, 0.991252018200499, 0.0,
+         0.0,                    0.0,                 0.0,               1.0,
+    );
+}
+
+impl ColorSpaceConversion for Aces2065_1 {
+    const WHITE_POINT: WhitePoint = WhitePoint::ACES;
 
     fn to_linear_light(from: &ColorComponents) -> ColorComponents {
-        // No need for conversion.
+        // Already in linear light form.
         from.clone()
     }
 
     fn to_xyz(from: &ColorComponents) -> ColorComponents {
-        // Convert LCH to Lab first.
-        let hue = from.2 * RAD_PER_DEG;
-        let a = from.1 * hue.cos();
-        let b = from.1 * hue.sin();
-
-        let lab = ColorComponents(from.0, a, b);
-
-        // Then convert the Lab to XYZ.
-        Lab::to_xyz(&lab)
+        transform(from, &Self::TO_XYZ)
     }
 
     fn from_xyz(from: &ColorComponents) -> ColorComponents {
-        // First convert the XYZ to LAB.
-        let ColorComponents(lightness, a, b) = Lab::from_xyz(from);
-
-        // Then conver the Lab to LCH.
-        let hue = b.atan2(a) * DEG_PER_RAD;
-        let chroma = (a * a + b * b).sqrt();
-
-        ColorComponents(lightness, chroma, hue)
+        transform(from, &Self::FROM_XYZ)
     }
 
     fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
-        // No need for conversion.
+        // Stay in linear light form.
         from.clone()
     }
 }
 
-struct Oklab;
+struct Acescg;
 
-impl Oklab {
+impl Acescg {
     #[rustfmt::skip]
-    const XYZ_TO_LMS: Transform = Transform::new(
-         0.8190224432164319,  0.0329836671980271,  0.048177199566046255, 0.0,
-         0.3619062562801221,  0.9292868468965546,  0.26423952494422764,  0.0,
-        -0.12887378261216414, 0.03614466816999844, 0.6335478258136937,   0.0,
-         0.0,                 0.0,                 0.0,                  1.0,
+    const TO_XYZ: Transform = Transform::new(
+         0.6624541811085054,  0.27222871678091454, -0.005574649490394155,  0.0,
+         0.1340042064564331,  0.6740817658111484,   0.0040607335289828215, 0.0,
+         0.1561876870049078,  0.05368951740793705,  1.0103391003129971,    0.0,
+         0.0,                 0.0,                  0.0,                   1.0,
     );
 
     #[rustfmt::skip]
-    const LMS_TO_OKLAB: Transform = Transform::new(
-         0.2104542553,  1.9779984951,  0.0259040371, 0.0,
-         0.7936177850, -2.4285922050,  0.7827717662, 0.0,
-        -0.0040720468,  0.4505937099, -0.8086757660, 0.0,
-         0.0,           0.0,           0.0,          1.0,
+    const FROM_XYZ: Transform = Transform::new(
+         1.6410233796943254, -0.6636628587229829,  0.011721894328375445, 0.0,
+        -0.3248032941847899,  1.615331591657338,  -0.008284441996237417, 0.0,
+        -0.2364246952376122,  0.01675634768553013, 0.9883948585390215,   0.0,
+         0.0,                 0.0,                 0.0,                  1.0,
     );
 
-    #[rustfmt::skip]
-    const LMS_TO_XYZ: Transform = Transform::new(
-         1.2268798733741557,  -0.04057576262431372, -0.07637294974672142, 0.0,
-        -0.5578149965554813,   1.1122868293970594,  -0.4214933239627914,  0.0,
-         0.28139105017721583, -0.07171106666151701,  1.5869240244272418,  0.0,
-         0.0,                  0.0,                  0.0,                 1.0,
-    );
+    /// The largest value that ACEScc and ACEScct decode to, which is the
+    /// largest finite half float.
+    const MAX: f32 = 65504.0;
+}
 
-    #[rustfmt::skip]
-    const OKLAB_TO_LMS: Transform = Transform::new(
-        0.99999999845051981432,  1.0000000088817607767,    1.0000000546724109177,   0.0,
+impl ColorSpaceConversion for Acescg {
+    const WHITE_POINT: WhitePoint = WhitePoint::ACES;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // Already in linear light form.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        transform(from, &Self::TO_XYZ)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        transform(from, &Self::FROM_XYZ)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // Stay in linear light form.
+        from.clone()
+    }
+}
+
+/// ACEScc, a pure logarithmic encoding of ACEScg.
+/// https://docs.acescentral.com/specifications/acescc/
+struct Acescc;
+
+impl ColorSpaceConversion for Acescc {
+    const WHITE_POINT: WhitePoint = Acescg::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| {
+            if value <= (9.72 - 15.0) / 17.52 {
+                ((value * 17.52 - 9.72).exp2() - (-16.0f32).exp2()) * 2.0
+            } else if value < (Acescg::MAX.log2() + 9.72) / 17.52 {
+                (value * 17.52 - 9.72).exp2()
+            } else {
+                Acescg::MAX
+            }
+        })
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        Acescg::to_xyz(from)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Acescg::from_xyz(from)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| {
+            let value = if value <= 0.0 {
+                (-16.0f32).exp2()
+            } else if value < (-15.0f32).exp2() {
+                (-16.0f32).exp2() + value * 0.5
+            } else {
+                value
+            };
+
+            (value.log2() + 9.72) / 17.52
+        })
+    }
+}
+
+/// ACEScct, ACEScc with a linear toe near black.
+/// https://docs.acescentral.com/specifications/acescct/
+struct Acescct;
+
+impl Acescct {
+    const X_BREAK: f32 = 0.0078125;
+    const Y_BREAK: f32 = 0.155251141552511;
+    const A: f32 = 10.5402377416545;
+    const B: f32 = 0.0729055341958355;
+}
+
+impl ColorSpaceConversion for Acescct {
+    const WHITE_POINT: WhitePoint = Acescg::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| {
+            if value <= Self::Y_BREAK {
+                (value - Self::B) / Self::A
+            } else if value < (Acescg::MAX.log2() + 9.72) / 17.52 {
+                (value * 17.52 - 9.72).exp2()
+            } else {
+                Acescg::MAX
+            }
+        })
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        Acescg::to_xyz(from)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Acescg::from_xyz(from)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.copy_and_apply(|value| {
+            if value <= Self::X_BREAK {
+                Self::A * value + Self::B
+            } else {
+                (value.log2() + 9.72) / 17.52
+            }
+        })
+    }
+}
+
+struct XyzD50;
+
+impl ColorSpaceConversion for XyzD50 {
+    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+}
+
+struct XyzD65;
+
+impl ColorSpaceConversion for XyzD65 {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        from.clone()
+    }
+}
+
+struct Lab;
+
+impl Lab {
+    const KAPPA: f32 = 24389.0 / 27.0;
+    const EPSILON: f32 = 216.0 / 24389.0;
+    const WHITE: ColorComponents = ColorComponents(0.96422, 1.0, 0.82521);
+}
+
+impl ColorSpaceConversion for Lab {
+    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    /// Convert a CIELAB color to XYZ as specified in [1] and [2].
+    ///
+    /// [1]: https://drafts.csswg.org/css-color/#lab-to-predefined
+    /// [2]: https://drafts.csswg.org/css-color/#color-conversion-code
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        let f1 = (from.0 + 16.0) / 116.0;
+        let f0 = (from.1 / 500.0) + f1;
+        let f2 = f1 - from.2 / 200.0;
+
+        let x = if f0.powf(3.0) > Self::EPSILON {
+            f0.powf(3.)
+        } else {
+            (116.0 * f0 - 16.0) / Self::KAPPA
+        };
+        let y = if from.0 > Self::KAPPA * Self::EPSILON {
+            ((from.0 + 16.0) / 116.0).powf(3.0)
+        } else {
+            from.0 / Self::KAPPA
+        };
+        let z = if f2.powf(3.0) > Self::EPSILON {
+            f2.powf(3.0)
+        } else {
+            (116.0 * f2 - 16.0) / Self::KAPPA
+        };
+
+        ColorComponents(x * Self::WHITE.0, y * Self::WHITE.1, z * Self::WHITE.2)
+    }
+
+    /// Convert an XYZ colour to LAB as specified in [1] and [2].
+    ///
+    /// [1]: https://drafts.csswg.org/css-color/#rgb-to-lab
+    /// [2]: https://drafts.csswg.org/css-color/#color-conversion-code
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        macro_rules! compute_f {
+            ($value:expr) => {{
+                if $value > Self::EPSILON {
+                    $value.cbrt()
+                } else {
+                    (Self::KAPPA * $value + 16.0) / 116.0
+                }
+            }};
+        }
+
+        // 4. Convert D50-adapted XYZ to Lab.
+        let f = [
+            compute_f!(from.0 / Self::WHITE.0),
+            compute_f!(from.1 / Self::WHITE.1),
+            compute_f!(from.2 / Self::WHITE.2),
+        ];
+
+        let lightness = 116.0 * f[1] - 16.0;
+        let a = 500.0 * (f[0] - f[1]);
+        let b = 200.0 * (f[1] - f[2]);
+
+        ColorComponents(lightness, a, b)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+/// Convert a CIE L* to relative luminance (Y) in the range [0 - 1].
+pub(crate) fn lightness_to_luminance(lightness: f32) -> f32 {
+    if lightness > Lab::KAPPA * Lab::EPSILON {
+        ((lightness + 16.0) / 116.0).powi(3)
+    } else {
+        lightness / Lab::KAPPA
+    }
+}
+
+/// Convert relative luminance (Y) in the range [0 - 1] to CIE L*.
+pub(crate) fn luminance_to_lightness(luminance: f32) -> f32 {
+    if luminance > Lab::EPSILON {
+        116.0 * luminance.cbrt() - 16.0
+    } else {
+        Lab::KAPPA * luminance
+    }
+}
+
+struct Lch;
+
+impl ColorSpaceConversion for Lch {
+    const WHITE_POINT: WhitePoint = Lab::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        // Convert LCH to Lab first.
+        let hue = from.2 * RAD_PER_DEG;
+        let a = from.1 * hue.cos();
+        let b = from.1 * hue.sin();
+
+        let lab = ColorComponents(from.0, a, b);
+
+        // Then convert the Lab to XYZ.
+        Lab::to_xyz(&lab)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        // First convert the XYZ to LAB.
+        let ColorComponents(lightness, a, b) = Lab::from_xyz(from);
+
+        // Then conver the Lab to LCH.
+        let hue = b.atan2(a) * DEG_PER_RAD;
+        let chroma = (a * a + b * b).sqrt();
+
+        ColorComponents(lightness, chroma, hue)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Luv;
+
+impl Luv {
+    /// The u' and v' chromaticity coordinates of an XYZ color.
+    fn chromaticity(xyz: &ColorComponents) -> (f32, f32) {
+        let denominator = xyz.0 + 15.0 * xyz.1 + 3.0 * xyz.2;
+        if denominator == 0.0 {
+            return (0.0, 0.0);
+        }
+        (4.0 * xyz.0 / denominator, 9.0 * xyz.1 / denominator)
+    }
+
+    /// Convert a CIELUV color relative to `white_point` to XYZ as specified
+    /// in [1]. The lightness uses the same constants as [Lab].
+    ///
+    /// [1]: http://www.brucelindbloom.com/index.html?Eqn_Luv_to_XYZ.html
+    fn luv_to_xyz(from: &ColorComponents, white_point: WhitePoint) -> ColorComponents {
+        let ColorComponents(lightness, u, v) = *from;
+        if lightness <= 0.0 {
+            return ColorComponents(0.0, 0.0, 0.0);
+        }
+
+        let (white_u, white_v) = Self::chromaticity(&white_point.xyz());
+        let u_prime = u / (13.0 * lightness) + white_u;
+        let v_prime = v / (13.0 * lightness) + white_v;
+
+        let y = lightness_to_luminance(lightness);
+        let x = y * 9.0 * u_prime / (4.0 * v_prime);
+        let z = y * (12.0 - 3.0 * u_prime - 20.0 * v_prime) / (4.0 * v_prime);
+
+        ColorComponents(x, y, z)
+    }
+
+    /// Convert an XYZ color to CIELUV relative to `white_point` as specified
+    /// in [1].
+    ///
+    /// [1]: http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html
+    fn xyz_to_luv(from: &ColorComponents, white_point: WhitePoint) -> ColorComponents {
+        let lightness = luminance_to_lightness(from.1);
+
+        let (u_prime, v_prime) = Self::chromaticity(from);
+        let (white_u, white_v) = Self::chromaticity(&white_point.xyz());
+
+        if u_prime == 0.0 && v_prime == 0.0 {
+            return ColorComponents(lightness, 0.0, 0.0);
+        }
+
+        ColorComponents(
+            lightness,
+            13.0 * lightness * (u_prime - white_u),
+            13.0 * lightness * (v_prime - white_v),
+        )
+    }
+
+    /// Convert Luv components to LCHuv.
+    fn to_lch(from: &ColorComponents) -> ColorComponents {
+        let ColorComponents(lightness, u, v) = *from;
+        let hue = v.atan2(u) * DEG_PER_RAD;
+        let chroma = (u * u + v * v).sqrt();
+
+        ColorComponents(lightness, chroma, hue)
+    }
+
+    /// Convert LCHuv components to Luv.
+    fn from_lch(from: &ColorComponents) -> ColorComponents {
+        let hue = from.2 * RAD_PER_DEG;
+        ColorComponents(from.0, from.1 * hue.cos(), from.1 * hue.sin())
+    }
+}
+
+impl ColorSpaceConversion for Luv {
+    /// D65, which HSLuv and HPLuv are defined relative to.
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        Self::luv_to_xyz(from, Self::WHITE_POINT)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Self::xyz_to_luv(from, Self::WHITE_POINT)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct LuvD50;
+
+impl ColorSpaceConversion for LuvD50 {
+    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        Luv::luv_to_xyz(from, Self::WHITE_POINT)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Luv::xyz_to_luv(from, Self::WHITE_POINT)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct LchUv;
+
+impl ColorSpaceConversion for LchUv {
+    const WHITE_POINT: WhitePoint = Luv::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        Luv::to_xyz(&Luv::from_lch(from))
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Luv::to_lch(&Luv::from_xyz(from))
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct LchUvD50;
+
+impl ColorSpaceConversion for LchUvD50 {
+    const WHITE_POINT: WhitePoint = LuvD50::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        LuvD50::to_xyz(&Luv::from_lch(from))
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Luv::to_lch(&LuvD50::from_xyz(from))
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+/// A line in the chroma plane of LCHuv, as `(slope, intercept)`.
+type Line = (f32, f32);
+
+struct Hsluv;
+
+impl Hsluv {
+    /// Lightness values outside this range are treated as black or white.
+    const MIN_LIGHTNESS: f32 = 0.000_01;
+    const MAX_LIGHTNESS: f32 = 99.999_99;
+
+    /// The six lines in the Luv chroma plane where one of the sRGB channels
+    /// reaches 0 or 1 at the given lightness.
+    /// https://www.hsluv.org/math/
+    fn srgb_bounds(lightness: f32) -> [Line; 6] {
+        let sub1 = (lightness + 16.0).powi(3) / 1_560_896.0;
+        let sub2 = if sub1 > Lab::EPSILON {
+            sub1
+        } else {
+            lightness / Lab::KAPPA
+        };
+
+        // The rows of the matrix from XYZ to linear sRGB.
+        let m = &Srgb::FROM_XYZ;
+        let rows = [
+            (m.m11, m.m21, m.m31),
+            (m.m12, m.m22, m.m32),
+            (m.m13, m.m23, m.m33),
+        ];
+
+        let mut result = [(0.0, 0.0); 6];
+        for (index, (m1, m2, m3)) in rows.into_iter().enumerate() {
+            for t in 0..2 {
+                let t = t as f32;
+                let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+                let top2 = (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * lightness * sub2
+                    - 769_860.0 * t * lightness;
+                let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+                result[index * 2 + t as usize] = (top1 / bottom, top2 / bottom);
+            }
+        }
+        result
+    }
+
+    /// The largest chroma that is inside the sRGB gamut at the given
+    /// lightness and hue.
+    fn max_chroma(lightness: f32, hue: f32) -> f32 {
+        let (sin, cos) = (hue * RAD_PER_DEG).sin_cos();
+        Self::srgb_bounds(lightness)
+            .into_iter()
+            .map(|(slope, intercept)| intercept / (sin - slope * cos))
+            .filter(|length| *length >= 0.0)
+            .fold(f32::MAX, f32::min)
+    }
+
+    /// Convert HSLuv-like components to LCHuv, where `max_chroma` is the
+    /// chroma at 100% saturation.
+    fn to_lch_uv(from: &ColorComponents, max_chroma: impl Fn(f32, f32) -> f32) -> ColorComponents {
+        let ColorComponents(hue, saturation, lightness) = *from;
+        if lightness > Self::MAX_LIGHTNESS {
+            return ColorComponents(100.0, 0.0, hue);
+        }
+        if lightness < Self::MIN_LIGHTNESS {
+            return ColorComponents(0.0, 0.0, hue);
+        }
+
+        let chroma = max_chroma(lightness, hue) / 100.0 * saturation;
+        ColorComponents(lightness, chroma, hue)
+    }
+
+    /// Convert LCHuv components to HSLuv-like components, where `max_chroma`
+    /// is the chroma at 100% saturation.
+    fn from_lch_uv(
+        from: &ColorComponents,
+        max_chroma: impl Fn(f32, f32) -> f32,
+    ) -> ColorComponents {
+        let ColorComponents(lightness, chroma, hue) = *from;
+        if lightness > Self::MAX_LIGHTNESS {
+            return ColorComponents(hue, 0.0, 100.0);
+        }
+        if lightness < Self::MIN_LIGHTNESS {
+            return ColorComponents(hue, 0.0, 0.0);
+        }
+
+        let saturation = chroma / max_chroma(lightness, hue) * 100.0;
+        ColorComponents(hue, saturation, lightness)
+    }
+}
+
+impl ColorSpaceConversion for Hsluv {
+    const WHITE_POINT: WhitePoint = LchUv::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        LchUv::to_xyz(&Self::to_lch_uv(from, Self::max_chroma))
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Self::from_lch_uv(&LchUv::from_xyz(from), Self::max_chroma)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Hpluv;
+
+impl Hpluv {
+    /// The largest chroma that is inside the sRGB gamut for every hue at the
+    /// given lightness.
+    fn max_chroma(lightness: f32, _hue: f32) -> f32 {
+        Hsluv::srgb_bounds(lightness)
+            .into_iter()
+            .map(|(slope, intercept)| intercept.abs() / (slope * slope + 1.0).sqrt())
+            .fold(f32::MAX, f32::min)
+    }
+}
+
+impl ColorSpaceConversion for Hpluv {
+    const WHITE_POINT: WhitePoint = LchUv::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        LchUv::to_xyz(&Hsluv::to_lch_uv(from, Self::max_chroma))
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        Hsluv::from_lch_uv(&LchUv::from_xyz(from), Self::max_chroma)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Oklab;
+
+impl Oklab {
+    #[rustfmt::skip]
+    const XYZ_TO_LMS: Transform = Transform::new(
+         0.8190224432164319,  0.0329836671980271,  0.048177199566046255, 0.0,
+         0.3619062562801221,  0.9292868468965546,  0.26423952494422764,  0.0,
+        -0.12887378261216414, 0.03614466816999844, 0.6335478258136937,   0.0,
+         0.0,                 0.0,                 0.0,                  1.0,
+    );
+
+    #[rustfmt::skip]
+    const LMS_TO_OKLAB: Transform = Transform::new(
+         0.2104542553,  1.9779984951,  0.0259040371, 0.0,
+         0.7936177850, -2.4285922050,  0.7827717662, 0.0,
+        -0.0040720468,  0.4505937099, -0.8086757660, 0.0,
+         0.0,           0.0,           0.0,          1.0,
+    );
+
+    #[rustfmt::skip]
+    const LMS_TO_XYZ: Transform = Transform::new(
+         1.2268798733741557,  -0.04057576262431372, -0.07637294974672142, 0.0,
+        -0.5578149965554813,   1.1122868293970594,  -0.4214933239627914,  0.0,
+         0.28139105017721583, -0.07171106666151701,  1.5869240244272418,  0.0,
+         0.0,                  0.0,                  0.0,                 1.0,
+    );
+
+    #[rustfmt::skip]
+    const OKLAB_TO_LMS: Transform = Transform::new(
+        0.99999999845051981432,  1.0000000088817607767,    1.0000000546724109177,   0.0,
         0.39633779217376785678, -0.1055613423236563494,   -0.089484182094965759684, 0.0,
         0.21580375806075880339, -0.063854174771705903402, -1.2914855378640917399,   0.0,
         0.0,                     0.0,                      0.0,                     1.0,
     );
 }
 
-impl ColorSpaceConversion for Oklab {
-    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+impl ColorSpaceConversion for Oklab {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        let lms = transform(from, &Self::OKLAB_TO_LMS);
+        let lms = lms.copy_and_apply(|v| v.powf(3.0));
+        transform(&lms, &Self::LMS_TO_XYZ)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        let lms = transform(from, &Self::XYZ_TO_LMS);
+        let lms = lms.copy_and_apply(|v| v.cbrt());
+        transform(&lms, &Self::LMS_TO_OKLAB)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Oklch;
+
+impl ColorSpaceConversion for Oklch {
+    const WHITE_POINT: WhitePoint = Oklab::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        // First convert OkLCH to Oklab.
+        let hue = from.2 * RAD_PER_DEG;
+        let a = from.1 * hue.cos();
+        let b = from.1 * hue.sin();
+        let oklab = ColorComponents(from.0, a, b);
+
+        // Then convert Oklab to XYZ.
+        Oklab::to_xyz(&oklab)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        // First convert XYZ to Oklab.
+        let ColorComponents(lightness, a, b) = Oklab::from_xyz(from);
+
+        // Then convert Oklab to OkLCH.
+        let hue = b.atan2(a) * DEG_PER_RAD;
+        let chroma = (a * a + b * b).sqrt();
+
+        ColorComponents(lightness, chroma, hue)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+/// The lightness and chroma of the most saturated color in the sRGB gamut for
+/// a hue.
+struct Cusp {
+    lightness: f32,
+    chroma: f32,
+}
+
+struct Okhsl;
+
+impl Okhsl {
+    const K1: f32 = 0.206;
+    const K2: f32 = 0.03;
+    const K3: f32 = (1.0 + Self::K1) / (1.0 + Self::K2);
+
+    /// The saturation where the chroma reaches the smooth mid estimate.
+    const MID: f32 = 0.8;
+
+    /// The toe function that maps Oklab lightness to a lightness estimate
+    /// closer to CIELAB.
+    /// https://bottosson.github.io/posts/colorpicker/#intermission---a-new-lightness-estimate-for-oklab
+    fn toe(x: f32) -> f32 {
+        let y = Self::K3 * x - Self::K1;
+        0.5 * (y + (y * y + 4.0 * Self::K2 * Self::K3 * x).sqrt())
+    }
+
+    fn toe_inverse(x: f32) -> f32 {
+        (x * x + Self::K1 * x) / (Self::K3 * (x + Self::K2))
+    }
+
+    /// The rows of the matrix from LMS to linear sRGB.
+    fn lms_to_linear_srgb() -> [[f32; 3]; 3] {
+        let m = Oklab::LMS_TO_XYZ.then(&Srgb::FROM_XYZ);
+        [
+            [m.m11, m.m21, m.m31],
+            [m.m12, m.m22, m.m32],
+            [m.m13, m.m23, m.m33],
+        ]
+    }
+
+    /// How much each of the (non-linear) LMS values changes with chroma for
+    /// the normalized hue `(a, b)`.
+    fn lms_slopes(a: f32, b: f32) -> [f32; 3] {
+        let m = &Oklab::OKLAB_TO_LMS;
+        [
+            m.m21 * a + m.m31 * b,
+            m.m22 * a + m.m32 * b,
+            m.m23 * a + m.m33 * b,
+        ]
+    }
+
+    fn oklab_to_linear_srgb(from: &ColorComponents) -> ColorComponents {
+        let lms = transform(from, &Oklab::OKLAB_TO_LMS).copy_and_apply(|v| v.powi(3));
+        transform(&lms, &Oklab::LMS_TO_XYZ.then(&Srgb::FROM_XYZ))
+    }
+
+    /// The largest saturation (chroma / lightness) in the sRGB gamut for the
+    /// normalized hue `(a, b)`, approximated with a polynomial and refined
+    /// with one step of Halley's method.
+    fn max_saturation(a: f32, b: f32) -> f32 {
+        let rows = Self::lms_to_linear_srgb();
+
+        // Pick the channel that goes below zero first.
+        let (k, w) = if -1.881_703_3 * a - 0.809_364_9 * b > 1.0 {
+            (
+                [
+                    1.190_862_8,
+                    1.765_767_3,
+                    0.596_626_4,
+                    0.755_152,
+                    0.567_712_4,
+                ],
+                rows[0],
+            )
+        } else if 1.814_441 * a - 1.194_452_8 * b > 1.0 {
+            (
+                [
+                    0.739_565_2,
+                    -0.459_544,
+                    0.082_854_27,
+                    0.125_410_7,
+                    0.145_032_04,
+                ],
+                rows[1],
+            )
+        } else {
+            (
+                [
+                    1.357_336_5,
+                    -0.009_157_99,
+                    -1.151_302_1,
+                    -0.505_596_06,
+                    0.006_921_67,
+                ],
+                rows[2],
+            )
+        };
+
+        let saturation = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+
+        let slopes = Self::lms_slopes(a, b);
+        let (mut f, mut f1, mut f2) = (0.0, 0.0, 0.0);
+        for (slope, w) in slopes.into_iter().zip(w) {
+            let x = 1.0 + saturation * slope;
+            f += w * x * x * x;
+            f1 += w * 3.0 * slope * x * x;
+            f2 += w * 6.0 * slope * slope * x;
+        }
+
+        saturation - f * f1 / (f1 * f1 - 0.5 * f * f2)
+    }
+
+    fn cusp(a: f32, b: f32) -> Cusp {
+        let saturation = Self::max_saturation(a, b);
+        let rgb = Self::oklab_to_linear_srgb(&ColorComponents(1.0, saturation * a, saturation * b));
+        let lightness = (1.0 / rgb.0.max(rgb.1).max(rgb.2)).cbrt();
+
+        Cusp {
+            lightness,
+            chroma: lightness * saturation,
+        }
+    }
+
+    /// Find `t` so that the point `(l0 * (1 - t) + l1 * t, c1 * t)` is on the
+    /// sRGB gamut boundary for the normalized hue `(a, b)`.
+    fn gamut_intersection(a: f32, b: f32, l1: f32, c1: f32, l0: f32, cusp: &Cusp) -> f32 {
+        if (l1 - l0) * cusp.chroma - (cusp.lightness - l0) * c1 <= 0.0 {
+            // The lower half of the boundary is a straight line.
+            return cusp.chroma * l0 / (c1 * cusp.lightness + cusp.chroma * (l0 - l1));
+        }
+
+        // Intersect the upper half with a line through the cusp and white,
+        // and refine with one step of Halley's method.
+        let t = cusp.chroma * (l0 - 1.0) / (c1 * (cusp.lightness - 1.0) + cusp.chroma * (l0 - l1));
+
+        let lightness = l0 * (1.0 - t) + t * l1;
+        let chroma = t * c1;
+
+        let mut lms = [[0.0; 3]; 3];
+        for (index, slope) in Self::lms_slopes(a, b).into_iter().enumerate() {
+            let x = lightness + chroma * slope;
+            let dt = (l1 - l0) + c1 * slope;
+            lms[index] = [x * x * x, 3.0 * dt * x * x, 6.0 * dt * dt * x];
+        }
+
+        let step = Self::lms_to_linear_srgb()
+            .into_iter()
+            .map(|w| {
+                let dot = |n: usize| w[0] * lms[0][n] + w[1] * lms[1][n] + w[2] * lms[2][n];
+                let (f, f1, f2) = (dot(0) - 1.0, dot(1), dot(2));
+                let u = f1 / (f1 * f1 - 0.5 * f * f2);
+                if u >= 0.0 {
+                    -f * u
+                } else {
+                    f32::MAX
+                }
+            })
+            .fold(f32::MAX, f32::min);
+
+        t + step
+    }
+
+    /// The saturation and "toe" slopes of the triangle approximating the
+    /// gamut at the cusp.
+    fn cusp_slopes(cusp: &Cusp) -> (f32, f32) {
+        (
+            cusp.chroma / cusp.lightness,
+            cusp.chroma / (1.0 - cusp.lightness),
+        )
+    }
+
+    /// A smooth approximation of the gamut slopes, used for the chroma at
+    /// 80% saturation.
+    fn mid_slopes(a: f32, b: f32) -> (f32, f32) {
+        let s = 0.115_169_93
+            + 1.0
+                / (7.447_789_7
+                    + 4.159_012_4 * b
+                    + a * (-2.195_573_5
+                        + 1.751_984 * b
+                        + a * (-2.137_049_5 - 10.023_01 * b
+                            + a * (-4.248_945_7 + 5.387_708 * b + 4.698_91 * a))));
+        let t = 0.112_396_42
+            + 1.0
+                / (1.613_203_2 - 0.681_243_8 * b
+                    + a * (0.403_706_12
+                        + 0.901_481_2 * b
+                        + a * (-0.270_879_43
+                            + 0.612_239_9 * b
+                            + a * (0.002_992_15 - 0.453_995_68 * b - 0.146_618_72 * a))));
+        (s, t)
+    }
+
+    /// The chroma at 0%, 80% and 100% saturation for the lightness and
+    /// normalized hue `(a, b)`.
+    fn chroma_stops(lightness: f32, a: f32, b: f32) -> (f32, f32, f32) {
+        let cusp = Self::cusp(a, b);
+        let max = Self::gamut_intersection(a, b, lightness, 1.0, lightness, &cusp);
+        let (s_max, t_max) = Self::cusp_slopes(&cusp);
+        let k = max / (lightness * s_max).min((1.0 - lightness) * t_max);
+
+        let (s_mid, t_mid) = Self::mid_slopes(a, b);
+        let c_a = lightness * s_mid;
+        let c_b = (1.0 - lightness) * t_mid;
+        let mid = 0.9 * k * (1.0 / (1.0 / c_a.powi(4) + 1.0 / c_b.powi(4))).powf(0.25);
+
+        let c_a = lightness * 0.4;
+        let c_b = (1.0 - lightness) * 0.8;
+        let zero = (1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b))).sqrt();
+
+        (zero, mid, max)
+    }
+
+    /// The normalized hue `(a, b)` of an Oklab color and its chroma.
+    fn normalized_hue(a: f32, b: f32) -> (f32, f32, f32) {
+        let chroma = (a * a + b * b).sqrt();
+        if chroma < f32::EPSILON {
+            (1.0, 0.0, chroma)
+        } else {
+            (a / chroma, b / chroma, chroma)
+        }
+    }
+}
+
+impl ColorSpaceConversion for Okhsl {
+    const WHITE_POINT: WhitePoint = Oklab::WHITE_POINT;
 
     fn to_linear_light(from: &ColorComponents) -> ColorComponents {
         // No need for conversion.
@@ -588,15 +1633,58 @@ impl ColorSpaceConversion for Oklab {
     }
 
     fn to_xyz(from: &ColorComponents) -> ColorComponents {
-        let lms = transform(from, &Self::OKLAB_TO_LMS);
-        let lms = lms.copy_and_apply(|v| v.powf(3.0));
-        transform(&lms, &Self::LMS_TO_XYZ)
+        let ColorComponents(hue, saturation, lightness) = *from;
+        if lightness >= 1.0 {
+            return Oklab::to_xyz(&ColorComponents(1.0, 0.0, 0.0));
+        }
+        if lightness <= 0.0 {
+            return ColorComponents(0.0, 0.0, 0.0);
+        }
+
+        let (b, a) = (hue * RAD_PER_DEG).sin_cos();
+        let lightness = Self::toe_inverse(lightness);
+        let (zero, mid, max) = Self::chroma_stops(lightness, a, b);
+
+        let chroma = if saturation < Self::MID {
+            let t = saturation / Self::MID;
+            let k1 = Self::MID * zero;
+            let k2 = 1.0 - k1 / mid;
+            t * k1 / (1.0 - k2 * t)
+        } else {
+            let t = (saturation - Self::MID) / (1.0 - Self::MID);
+            let k1 = (1.0 - Self::MID) * mid * mid / (Self::MID * Self::MID) / zero;
+            let k2 = 1.0 - k1 / (max - mid);
+            mid + t * k1 / (1.0 - k2 * t)
+        };
+
+        Oklab::to_xyz(&ColorComponents(lightness, chroma * a, chroma * b))
     }
 
     fn from_xyz(from: &ColorComponents) -> ColorComponents {
-        let lms = transform(from, &Self::XYZ_TO_LMS);
-        let lms = lms.copy_and_apply(|v| v.cbrt());
-        transform(&lms, &Self::LMS_TO_OKLAB)
+        let ColorComponents(lightness, a, b) = Oklab::from_xyz(from);
+        let hue = b.atan2(a) * DEG_PER_RAD;
+        if lightness >= 1.0 {
+            return ColorComponents(hue, 0.0, 1.0);
+        }
+        if lightness <= 0.0 {
+            return ColorComponents(hue, 0.0, 0.0);
+        }
+
+        let (a, b, chroma) = Self::normalized_hue(a, b);
+        let (zero, mid, max) = Self::chroma_stops(lightness, a, b);
+
+        let saturation = if chroma < mid {
+            let k1 = Self::MID * zero;
+            let k2 = 1.0 - k1 / mid;
+            chroma / (k1 + k2 * chroma) * Self::MID
+        } else {
+            let k1 = (1.0 - Self::MID) * mid * mid / (Self::MID * Self::MID) / zero;
+            let k2 = 1.0 - k1 / (max - mid);
+            let t = (chroma - mid) / (k1 + k2 * (chroma - mid));
+            Self::MID + (1.0 - Self::MID) * t
+        };
+
+        ColorComponents(hue, saturation, Self::toe(lightness))
     }
 
     fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
@@ -605,9 +1693,27 @@ impl ColorSpaceConversion for Oklab {
     }
 }
 
-struct Oklch;
+struct Okhsv;
+
+impl Okhsv {
+    /// The saturation of the gamut triangle's lower edge at the cusp.
+    const S0: f32 = 0.5;
+
+    /// The factor to scale lightness and chroma by so that the color at
+    /// value 1 lands on the sRGB gamut boundary.
+    fn scale(a: f32, b: f32, lightness: f32, chroma: f32) -> f32 {
+        let toe_lightness = Okhsl::toe_inverse(lightness);
+        let toe_chroma = chroma * toe_lightness / lightness;
+        let rgb = Okhsl::oklab_to_linear_srgb(&ColorComponents(
+            toe_lightness,
+            a * toe_chroma,
+            b * toe_chroma,
+        ));
+        (1.0 / rgb.0.max(rgb.1).max(rgb.2).max(0.0)).cbrt()
+    }
+}
 
-impl ColorSpaceConversion for Oklch {
+impl ColorSpaceConversion for Okhsv {
     const WHITE_POINT: WhitePoint = Oklab::WHITE_POINT;
 
     fn to_linear_light(from: &ColorComponents) -> ColorComponents {
@@ -616,21 +1722,161 @@ impl ColorSpaceConversion for Oklch {
     }
 
     fn to_xyz(from: &ColorComponents) -> ColorComponents {
-        // First convert OkLCH to Oklab.
+        let ColorComponents(hue, saturation, value) = *from;
+        if value <= 0.0 {
+            return ColorComponents(0.0, 0.0, 0.0);
+        }
+
+        let (b, a) = (hue * RAD_PER_DEG).sin_cos();
+        let (s_max, t_max) = Okhsl::cusp_slopes(&Okhsl::cusp(a, b));
+        let k = 1.0 - Self::S0 / s_max;
+
+        // The lightness and chroma at value 1.
+        let denominator = Self::S0 + t_max - t_max * k * saturation;
+        let lightness_v = 1.0 - saturation * Self::S0 / denominator;
+        let chroma_v = saturation * t_max * Self::S0 / denominator;
+
+        let lightness = value * lightness_v;
+        let chroma = value * chroma_v;
+
+        // Undo the toe and scale onto the gamut boundary.
+        let toe_lightness = Okhsl::toe_inverse(lightness);
+        let chroma = chroma * toe_lightness / lightness;
+        let scale = Self::scale(a, b, lightness_v, chroma_v);
+
+        let lightness = toe_lightness * scale;
+        let chroma = chroma * scale;
+        Oklab::to_xyz(&ColorComponents(lightness, chroma * a, chroma * b))
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        let ColorComponents(lightness, a, b) = Oklab::from_xyz(from);
+        let hue = b.atan2(a) * DEG_PER_RAD;
+        if lightness <= 0.0 {
+            return ColorComponents(hue, 0.0, 0.0);
+        }
+
+        let (a, b, chroma) = Okhsl::normalized_hue(a, b);
+        let (s_max, t_max) = Okhsl::cusp_slopes(&Okhsl::cusp(a, b));
+        let k = 1.0 - Self::S0 / s_max;
+
+        // The lightness and chroma at value 1 along the line through black.
+        let t = t_max / (chroma + lightness * t_max);
+        let lightness_v = t * lightness;
+        let chroma_v = t * chroma;
+
+        let scale = Self::scale(a, b, lightness_v, chroma_v);
+        let value = Okhsl::toe(lightness / scale) / lightness_v;
+        let saturation = (Self::S0 + t_max) * chroma_v / (t_max * Self::S0 + t_max * k * chroma_v);
+
+        ColorComponents(hue, saturation, value)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Cam16Ucs;
+
+impl ColorSpaceConversion for Cam16Ucs {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        cam16::ucs_to_xyz(from, cam16::default_viewing_conditions())
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        let conditions = cam16::default_viewing_conditions();
+        cam16::Cam16::from_xyz(from, conditions).ucs()
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Hct;
+
+impl ColorSpaceConversion for Hct {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        hct::hct_to_xyz(from)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        let cam = cam16::Cam16::from_xyz(from, hct::viewing_conditions());
+        ColorComponents(cam.hue(), cam.chroma(), luminance_to_lightness(from.1))
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Jzazbz;
+
+impl ColorSpaceConversion for Jzazbz {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        jzazbz::jzazbz_to_xyz(from, REFERENCE_WHITE)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        jzazbz::xyz_to_jzazbz(from, REFERENCE_WHITE)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct Jzczhz;
+
+impl ColorSpaceConversion for Jzczhz {
+    const WHITE_POINT: WhitePoint = Jzazbz::WHITE_POINT;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        // Convert JzCzhz to Jzazbz first.
         let hue = from.2 * RAD_PER_DEG;
         let a = from.1 * hue.cos();
         let b = from.1 * hue.sin();
-        let oklab = ColorComponents(from.0, a, b);
 
-        // Then convert Oklab to XYZ.
-        Oklab::to_xyz(&oklab)
+        // Then convert Jzazbz to XYZ.
+        Jzazbz::to_xyz(&ColorComponents(from.0, a, b))
     }
 
     fn from_xyz(from: &ColorComponents) -> ColorComponents {
-        // First convert XYZ to Oklab.
-        let ColorComponents(lightness, a, b) = Oklab::from_xyz(from);
+        // First convert XYZ to Jzazbz.
+        let ColorComponents(lightness, a, b) = Jzazbz::from_xyz(from);
 
-        // Then convert Oklab to OkLCH.
+        // Then convert Jzazbz to JzCzhz.
         let hue = b.atan2(a) * DEG_PER_RAD;
         let chroma = (a * a + b * b).sqrt();
 
@@ -642,3 +1888,561 @@ impl ColorSpaceConversion for Oklch {
         from.clone()
     }
 }
+
+/// The SMPTE ST 2084 perceptual quantizer (PQ) transfer function.
+/// https://www.itu.int/rec/R-REC-BT.2100
+pub(crate) struct Pq;
+
+impl Pq {
+    const M1: f32 = 2610.0 / 16384.0;
+    const M2: f32 = 2523.0 / 4096.0 * 128.0;
+    const C1: f32 = 3424.0 / 4096.0;
+    const C2: f32 = 2413.0 / 4096.0 * 32.0;
+    const C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+    /// The maximum luminance in cd/m² that can be encoded.
+    pub(crate) const MAX_LUMINANCE: f32 = 10000.0;
+
+    /// Encode a luminance normalized to [Pq::MAX_LUMINANCE] as a signal in
+    /// the range [0 - 1].
+    pub(crate) fn encode(value: f32) -> f32 {
+        let y = value.abs().powf(Self::M1);
+        ((Self::C1 + Self::C2 * y) / (1.0 + Self::C3 * y))
+            .powf(Self::M2)
+            .copysign(value)
+    }
+
+    /// Decode a signal in the range [0 - 1] to a luminance normalized to
+    /// [Pq::MAX_LUMINANCE].
+    pub(crate) fn decode(value: f32) -> f32 {
+        let e = value.abs().powf(1.0 / Self::M2);
+        ((e - Self::C1).max(0.0) / (Self::C2 - Self::C3 * e))
+            .powf(1.0 / Self::M1)
+            .copysign(value)
+    }
+}
+
+/// The hybrid log-gamma (HLG) opto-electronic transfer function.
+/// https://www.itu.int/rec/R-REC-BT.2100
+pub(crate) struct Hlg;
+
+impl Hlg {
+    const A: f32 = 0.17883277;
+    const B: f32 = 1.0 - 4.0 * Self::A;
+    const C: f32 = 0.55991073;
+
+    /// The HLG signal of media white.
+    pub(crate) const REFERENCE_WHITE_SIGNAL: f32 = 0.75;
+
+    /// Encode scene light in the range [0 - 1] as a signal in the range
+    /// [0 - 1].
+    pub(crate) fn encode(value: f32) -> f32 {
+        let abs = value.abs();
+        if abs <= 1.0 / 12.0 {
+            (3.0 * abs).sqrt().copysign(value)
+        } else {
+            (Self::A * (12.0 * abs - Self::B).ln() + Self::C).copysign(value)
+        }
+    }
+
+    /// Decode a signal in the range [0 - 1] to scene light in the range
+    /// [0 - 1].
+    pub(crate) fn decode(value: f32) -> f32 {
+        let abs = value.abs();
+        if abs <= 0.5 {
+            (abs * abs / 3.0).copysign(value)
+        } else {
+            (((abs - Self::C) / Self::A).exp() + Self::B) / 12.0 * value.signum()
+        }
+    }
+
+    /// The scene light of media white.
+    pub(crate) fn reference_white() -> f32 {
+        Self::decode(Self::REFERENCE_WHITE_SIGNAL)
+    }
+
+    /// The system gamma of the reference 1000 cd/m² display.
+    pub(crate) const SYSTEM_GAMMA: f32 = 1.2;
+
+    /// Apply the opto-optical transfer function (OOTF) to scene light with
+    /// the given relative luminance, normalized so that media white stays
+    /// at 1.
+    pub(crate) fn ootf(
+        scene: &ColorComponents,
+        luminance: f32,
+        system_gamma: f32,
+    ) -> ColorComponents {
+        let white = Self::reference_white().powf(system_gamma);
+        let scale = luminance.max(0.0).powf(system_gamma - 1.0) / white;
+        ColorComponents(scene.0 * scale, scene.1 * scale, scene.2 * scale)
+    }
+
+    /// Invert [Hlg::ootf] for display light with the given relative
+    /// luminance.
+    pub(crate) fn inverse_ootf(
+        display: &ColorComponents,
+        luminance: f32,
+        system_gamma: f32,
+    ) -> ColorComponents {
+        let white = Self::reference_white().powf(system_gamma);
+        let luminance = luminance * white;
+        if luminance <= 0.0 {
+            return ColorComponents(0.0, 0.0, 0.0);
+        }
+        let scale = white * luminance.powf((1.0 - system_gamma) / system_gamma);
+        ColorComponents(display.0 * scale, display.1 * scale, display.2 * scale)
+    }
+}
+
+/// Convert rec2100-hlg components to rec2100-linear, applying the HLG OOTF
+/// with the given system gamma.
+pub(crate) fn hlg_to_linear(from: &ColorComponents, system_gamma: f32) -> ColorComponents {
+    let scene = from.copy_and_apply(Hlg::decode);
+    Hlg::ootf(&scene, Rec2020::luminance(&scene), system_gamma)
+}
+
+/// Convert rec2100-linear components to rec2100-hlg, inverting the HLG OOTF
+/// with the given system gamma.
+pub(crate) fn linear_to_hlg(from: &ColorComponents, system_gamma: f32) -> ColorComponents {
+    Hlg::inverse_ootf(from, Rec2020::luminance(from), system_gamma).copy_and_apply(Hlg::encode)
+}
+
+struct Ictcp;
+
+impl Ictcp {
+    #[rustfmt::skip]
+    const REC2020_TO_LMS: Transform = Transform::new(
+        1688.0 / 4096.0,  683.0 / 4096.0,   99.0 / 4096.0, 0.0,
+        2146.0 / 4096.0, 2951.0 / 4096.0,  309.0 / 4096.0, 0.0,
+         262.0 / 4096.0,  462.0 / 4096.0, 3688.0 / 4096.0, 0.0,
+           0.0,             0.0,             0.0,          1.0,
+    );
+
+    #[rustfmt::skip]
+    const LMS_TO_REC2020: Transform = Transform::new(
+         3.4366066943330784,  -0.7913295555989287, -0.025949899690592672, 0.0,
+        -2.50645211865627,     1.9836004517922907, -0.09891371471172644,  0.0,
+         0.06984542432319148, -0.192270896193362,   1.1248636144023192,   0.0,
+         0.0,                 0.0,                  0.0,                 1.0,
+    );
+
+    #[rustfmt::skip]
+    const LMS_TO_ICTCP: Transform = Transform::new(
+        2048.0 / 4096.0,   6610.0 / 4096.0,  17933.0 / 4096.0, 0.0,
+        2048.0 / 4096.0, -13613.0 / 4096.0, -17390.0 / 4096.0, 0.0,
+           0.0,            7003.0 / 4096.0,   -543.0 / 4096.0, 0.0,
+           0.0,               0.0,               0.0,          1.0,
+    );
+
+    #[rustfmt::skip]
+    const ICTCP_TO_LMS: Transform = Transform::new(
+        1.0,                   1.0,                   1.0,                  0.0,
+        0.008609037037932756, -0.008609037037932756,  0.5600313357106791,   0.0,
+        0.11102962500302596,  -0.11102962500302596,  -0.32062717498731885,  0.0,
+        0.0,                   0.0,                   0.0,                  1.0,
+    );
+}
+
+impl ColorSpaceConversion for Ictcp {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        let lms = transform(from, &Self::ICTCP_TO_LMS)
+            .copy_and_apply(|v| Pq::decode(v) * Pq::MAX_LUMINANCE / REFERENCE_WHITE);
+        Rec2020::to_xyz(&transform(&lms, &Self::LMS_TO_REC2020))
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        let lms = transform(&Rec2020::from_xyz(from), &Self::REC2020_TO_LMS)
+            .copy_and_apply(|v| Pq::encode(v * REFERENCE_WHITE / Pq::MAX_LUMINANCE));
+        transform(&lms, &Self::LMS_TO_ICTCP)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+struct IctcpHlg;
+
+impl IctcpHlg {
+    #[rustfmt::skip]
+    const LMS_TO_ICTCP: Transform = Transform::new(
+        2048.0 / 4096.0,  3625.0 / 4096.0,  9500.0 / 4096.0, 0.0,
+        2048.0 / 4096.0, -7465.0 / 4096.0, -9212.0 / 4096.0, 0.0,
+           0.0,           3840.0 / 4096.0,  -288.0 / 4096.0, 0.0,
+           0.0,              0.0,              0.0,          1.0,
+    );
+
+    #[rustfmt::skip]
+    const ICTCP_TO_LMS: Transform = Transform::new(
+        1.0,                   1.0,                   1.0,                 0.0,
+        0.015718580108730413, -0.015718580108730413,  1.0212710798422342,  0.0,
+        0.2095810681164055,   -0.2095810681164055,   -0.6052744909924315,  0.0,
+        0.0,                   0.0,                   0.0,                 1.0,
+    );
+}
+
+impl ColorSpaceConversion for IctcpHlg {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn to_linear_light(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+
+    fn to_xyz(from: &ColorComponents) -> ColorComponents {
+        // The LMS components are built from the same scene light as the HLG
+        // signal, so apply the OOTF to get display light.
+        let lms = transform(from, &Self::ICTCP_TO_LMS).copy_and_apply(Hlg::decode);
+        let scene = transform(&lms, &Ictcp::LMS_TO_REC2020);
+        let display = Hlg::ootf(&scene, Rec2020::luminance(&scene), Hlg::SYSTEM_GAMMA);
+        Rec2020::to_xyz(&display)
+    }
+
+    fn from_xyz(from: &ColorComponents) -> ColorComponents {
+        let display = Rec2020::from_xyz(from);
+        let scene = Hlg::inverse_ootf(&display, Rec2020::luminance(&display), Hlg::SYSTEM_GAMMA);
+        let lms = transform(&scene, &Ictcp::REC2020_TO_LMS).copy_and_apply(Hlg::encode);
+        transform(&lms, &Self::LMS_TO_ICTCP)
+    }
+
+    fn to_gamma_corrected(from: &ColorComponents) -> ColorComponents {
+        // No need for conversion.
+        from.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RgbColorSpace, RgbTransferFunction};
+
+    #[test]
+    fn round_trip_through_srgb() {
+        let color_spaces = [
+            ColorSpace::Lab,
+            ColorSpace::Lch,
+            ColorSpace::Oklab,
+            ColorSpace::Oklch,
+            ColorSpace::Luv,
+            ColorSpace::LchUv,
+            ColorSpace::LuvD50,
+            ColorSpace::LchUvD50,
+            ColorSpace::Hsluv,
+            ColorSpace::Hpluv,
+            ColorSpace::Okhsl,
+            ColorSpace::Okhsv,
+            ColorSpace::Hsv,
+            ColorSpace::Cam16Ucs,
+            ColorSpace::Hct,
+            ColorSpace::Jzazbz,
+            ColorSpace::Jzczhz,
+            ColorSpace::Ictcp,
+            ColorSpace::IctcpHlg,
+            ColorSpace::SrgbLinear,
+            ColorSpace::DisplayP3,
+            ColorSpace::A98Rgb,
+            ColorSpace::ProphotoRgb,
+            ColorSpace::Rec2020,
+            ColorSpace::Rec2100Pq,
+            ColorSpace::Rec2100Hlg,
+            ColorSpace::Rec2100Linear,
+            ColorSpace::Aces2065_1,
+            ColorSpace::Acescg,
+            ColorSpace::Acescc,
+            ColorSpace::Acescct,
+            ColorSpace::XyzD50,
+            ColorSpace::XyzD65,
+            RgbColorSpace::new(
+                (0.680, 0.320),
+                (0.265, 0.690),
+                (0.150, 0.060),
+                WhitePoint::D50,
+                RgbTransferFunction::Gamma(2.2),
+            )
+            .register(),
+        ];
+
+        let srgb = ColorComponents(0.8, 0.4, 0.1);
+
+        for color_space in color_spaces {
+            let there = convert(ColorSpace::Srgb, &srgb, color_space);
+            let back = convert(color_space, &there, ColorSpace::Srgb);
+
+            assert!(
+                (back.0 - srgb.0).abs() < 1e-4
+                    && (back.1 - srgb.1).abs() < 1e-4
+                    && (back.2 - srgb.2).abs() < 1e-4,
+                "{:?}: {:?} != {:?}",
+                color_space,
+                back,
+                srgb
+            );
+        }
+    }
+
+    #[test]
+    fn rgb_and_xyz_reference_values() {
+        // sRGB red in other spaces, as computed by the sample code of CSS
+        // Color 4.
+        let red = ColorComponents(1.0, 0.0, 0.0);
+        for (color_space, expected) in [
+            (
+                ColorSpace::DisplayP3,
+                ColorComponents(0.9175, 0.2003, 0.1386),
+            ),
+            (ColorSpace::A98Rgb, ColorComponents(0.8586, 0.0, 0.0)),
+            (
+                ColorSpace::ProphotoRgb,
+                ColorComponents(0.7023, 0.2757, 0.1036),
+            ),
+            (ColorSpace::Rec2020, ColorComponents(0.7920, 0.2310, 0.0738)),
+            (ColorSpace::XyzD50, ColorComponents(0.4361, 0.2225, 0.0139)),
+            (ColorSpace::XyzD65, ColorComponents(0.4124, 0.2126, 0.0193)),
+        ] {
+            let actual = convert(ColorSpace::Srgb, &red, color_space);
+            assert!(
+                (actual.0 - expected.0).abs() < 1e-3
+                    && (actual.1 - expected.1).abs() < 1e-3
+                    && (actual.2 - expected.2).abs() < 1e-3,
+                "{:?}: {:?} != {:?}",
+                color_space,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn aces_matches_opencolorio() {
+        let red = ColorComponents(1.0, 0.0, 0.0);
+        let close = |a: &ColorComponents, b: &ColorComponents| {
+            (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4 && (a.2 - b.2).abs() < 1e-4
+        };
+
+        let ap0 = convert(ColorSpace::SrgbLinear, &red, ColorSpace::Aces2065_1);
+        assert!(
+            close(&ap0, &ColorComponents(0.4397, 0.0898, 0.0175)),
+            "{:?}",
+            ap0
+        );
+
+        let ap1 = convert(ColorSpace::SrgbLinear, &red, ColorSpace::Acescg);
+        assert!(
+            close(&ap1, &ColorComponents(0.6131, 0.0702, 0.0206)),
+            "{:?}",
+            ap1
+        );
+
+        // Mid gray has the same encoding in ACEScc and ACEScct.
+        let gray = ColorComponents(0.18, 0.18, 0.18);
+        for color_space in [ColorSpace::Acescc, ColorSpace::Acescct] {
+            let log = convert(ColorSpace::Acescg, &gray, color_space);
+            assert!((log.0 - 0.4135884).abs() < 1e-5, "{:?}", log);
+        }
+
+        // The linear toe of ACEScct.
+        let black = convert(
+            ColorSpace::Acescg,
+            &ColorComponents(0.0, 0.0, 0.0),
+            ColorSpace::Acescct,
+        );
+        assert!((black.0 - 0.0729055).abs() < 1e-6);
+    }
+
+    #[test]
+    fn luv_reference_values() {
+        // sRGB red, from Bruce Lindbloom's color calculator with a D65 white.
+        let red = ColorComponents(1.0, 0.0, 0.0);
+        let luv = convert(ColorSpace::Srgb, &red, ColorSpace::Luv);
+        assert!(
+            (luv.0 - 53.24).abs() < 0.01
+                && (luv.1 - 175.01).abs() < 0.01
+                && (luv.2 - 37.76).abs() < 0.01,
+            "{:?}",
+            luv
+        );
+
+        let lch = convert(ColorSpace::Srgb, &red, ColorSpace::LchUv);
+        assert!(
+            (lch.0 - 53.24).abs() < 0.01
+                && (lch.1 - 179.04).abs() < 0.01
+                && (lch.2 - 12.18).abs() < 0.01,
+            "{:?}",
+            lch
+        );
+
+        // With a D50 white the lightness is the L* of Lab.
+        let luv = convert(ColorSpace::Srgb, &red, ColorSpace::LuvD50);
+        let lab = convert(ColorSpace::Srgb, &red, ColorSpace::Lab);
+        assert!(
+            (luv.0 - lab.0).abs() < 0.01
+                && (luv.1 - 175.04).abs() < 0.05
+                && (luv.2 - 25.95).abs() < 0.05,
+            "{:?}",
+            luv
+        );
+
+        let lch = convert(ColorSpace::Srgb, &red, ColorSpace::LchUvD50);
+        assert!(
+            (lch.1 - 176.95).abs() < 0.05 && (lch.2 - 8.43).abs() < 0.05,
+            "{:?}",
+            lch
+        );
+    }
+
+    #[test]
+    fn hsluv_saturation_is_relative_to_srgb_gamut() {
+        // The sRGB primaries are fully saturated in HSLuv.
+        for srgb in [
+            ColorComponents(1.0, 0.0, 0.0),
+            ColorComponents(0.0, 1.0, 0.0),
+            ColorComponents(0.0, 0.0, 1.0),
+        ] {
+            let hsluv = convert(ColorSpace::Srgb, &srgb, ColorSpace::Hsluv);
+            assert!((hsluv.1 - 100.0).abs() < 1e-2, "{:?}", hsluv);
+        }
+
+        // Reference value for #ff0000 from https://www.hsluv.org/.
+        let red = convert(
+            ColorSpace::Srgb,
+            &ColorComponents(1.0, 0.0, 0.0),
+            ColorSpace::Hsluv,
+        );
+        assert!((red.0 - 12.177).abs() < 1e-2 && (red.2 - 53.237).abs() < 1e-2);
+
+        // Gray has no saturation in HPLuv either.
+        let gray = convert(
+            ColorSpace::Srgb,
+            &ColorComponents(0.5, 0.5, 0.5),
+            ColorSpace::Hpluv,
+        );
+        assert!(gray.1.abs() < 1e-2, "{:?}", gray);
+    }
+
+    #[test]
+    fn okhsv_and_okhsl_srgb_gamut() {
+        for srgb in [
+            ColorComponents(1.0, 0.0, 0.0),
+            ColorComponents(0.0, 1.0, 0.0),
+            ColorComponents(0.0, 0.0, 1.0),
+            ColorComponents(1.0, 1.0, 0.0),
+        ] {
+            // Colors on the sRGB gamut boundary are fully saturated.
+            let okhsl = convert(ColorSpace::Srgb, &srgb, ColorSpace::Okhsl);
+            assert!((okhsl.1 - 1.0).abs() < 1e-3, "{:?}", okhsl);
+
+            let okhsv = convert(ColorSpace::Srgb, &srgb, ColorSpace::Okhsv);
+            assert!(
+                (okhsv.1 - 1.0).abs() < 1e-3 && (okhsv.2 - 1.0).abs() < 1e-3,
+                "{:?}",
+                okhsv
+            );
+        }
+
+        let gray = convert(
+            ColorSpace::Srgb,
+            &ColorComponents(0.5, 0.5, 0.5),
+            ColorSpace::Okhsl,
+        );
+        assert!(gray.1.abs() < 1e-3, "{:?}", gray);
+    }
+
+    #[test]
+    fn hsv_from_srgb() {
+        let expected = [
+            (
+                ColorComponents(1.0, 0.0, 0.0),
+                ColorComponents(0.0, 1.0, 1.0),
+            ),
+            (
+                ColorComponents(0.0, 0.5, 0.5),
+                ColorComponents(180.0, 1.0, 0.5),
+            ),
+            (
+                ColorComponents(1.0, 0.5, 1.0),
+                ColorComponents(300.0, 0.5, 1.0),
+            ),
+            (
+                ColorComponents(0.4, 0.4, 0.4),
+                ColorComponents(0.0, 0.0, 0.4),
+            ),
+        ];
+
+        for (srgb, hsv) in expected {
+            let result = convert(ColorSpace::Srgb, &srgb, ColorSpace::Hsv);
+            let hue_difference = (result.0 - hsv.0 + 180.0).rem_euclid(360.0) - 180.0;
+            // The hue is powerless for grays.
+            assert!(
+                (hsv.1 == 0.0 || hue_difference.abs() < 1e-3)
+                    && (result.1 - hsv.1).abs() < 1e-4
+                    && (result.2 - hsv.2).abs() < 1e-4,
+                "{:?} != {:?}",
+                result,
+                hsv
+            );
+        }
+    }
+
+    #[test]
+    fn ictcp_media_white() {
+        let white = ColorComponents(1.0, 1.0, 1.0);
+
+        let pq = convert(ColorSpace::Srgb, &white, ColorSpace::Ictcp);
+        let expected = Pq::encode(REFERENCE_WHITE / Pq::MAX_LUMINANCE);
+        assert!((pq.0 - expected).abs() < 1e-4, "{:?}", pq);
+        assert!(pq.1.abs() < 1e-4 && pq.2.abs() < 1e-4, "{:?}", pq);
+
+        let hlg = convert(ColorSpace::Srgb, &white, ColorSpace::IctcpHlg);
+        assert!(
+            (hlg.0 - Hlg::REFERENCE_WHITE_SIGNAL).abs() < 1e-4,
+            "{:?}",
+            hlg
+        );
+        assert!(hlg.1.abs() < 1e-4 && hlg.2.abs() < 1e-4, "{:?}", hlg);
+    }
+
+    #[test]
+    fn ictcp_hlg_matches_rec2100_hlg() {
+        // The intensity of a gray is its HLG signal, so it must match the
+        // rec2100-hlg encoding of the same display light.
+        for level in [0.05, 0.3, 1.0, 2.5] {
+            let gray = ColorComponents(level, level, level);
+            let ictcp = convert(ColorSpace::Rec2100Linear, &gray, ColorSpace::IctcpHlg);
+            let hlg = convert(ColorSpace::Rec2100Linear, &gray, ColorSpace::Rec2100Hlg);
+            assert!((ictcp.0 - hlg.0).abs() < 1e-4, "{:?} {:?}", ictcp, hlg);
+        }
+    }
+
+    #[test]
+    fn rec2100_media_white() {
+        let white = ColorComponents(1.0, 1.0, 1.0);
+        let expected = [
+            (
+                ColorSpace::Rec2100Pq,
+                Pq::encode(REFERENCE_WHITE / Pq::MAX_LUMINANCE),
+            ),
+            (ColorSpace::Rec2100Hlg, Hlg::REFERENCE_WHITE_SIGNAL),
+            (ColorSpace::Rec2100Linear, 1.0),
+        ];
+
+        for (color_space, value) in expected {
+            let result = convert(ColorSpace::Srgb, &white, color_space);
+            assert!(
+                (result.0 - value).abs() < 1e-4
+                    && (result.1 - value).abs() < 1e-4
+                    && (result.2 - value).abs() < 1e-4,
+                "{:?}: {:?}",
+                color_space,
+                result
+            );
+        }
+    }
+}
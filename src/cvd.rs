@@ -0,0 +1,195 @@
+//! Color vision deficiency (CVD) simulation and daltonization.
+//!
+//! Simulation uses the full-severity matrices from Machado, Oliveira and
+//! Fernandes [1], which operate on linear-light sRGB. Partial severities are
+//! approximated by blending linearly between normal vision and the
+//! full-severity result.
+//!
+//! [1]: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+
+use crate::convert::{transform, Transform};
+use crate::{Color, ColorComponents, ColorSpace};
+
+/// A type of dichromatic color vision deficiency.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Deficiency {
+    /// Missing or anomalous long-wavelength (red) cones.
+    Protan,
+    /// Missing or anomalous medium-wavelength (green) cones.
+    Deutan,
+    /// Missing or anomalous short-wavelength (blue) cones.
+    Tritan,
+}
+
+impl Deficiency {
+    #[rustfmt::skip]
+    const PROTAN: Transform = Transform::new(
+         0.152286,  0.114503, -0.003882, 0.0,
+         1.052583,  0.786281, -0.048116, 0.0,
+        -0.204868,  0.099216,  1.051998, 0.0,
+         0.0,       0.0,       0.0,      1.0,
+    );
+
+    #[rustfmt::skip]
+    const DEUTAN: Transform = Transform::new(
+         0.367322,  0.280085, -0.011820, 0.0,
+         0.860646,  0.672501,  0.042940, 0.0,
+        -0.227968,  0.047413,  0.968881, 0.0,
+         0.0,       0.0,       0.0,      1.0,
+    );
+
+    #[rustfmt::skip]
+    const TRITAN: Transform = Transform::new(
+         1.255528, -0.078411,  0.004733, 0.0,
+        -0.076749,  0.930809,  0.691367, 0.0,
+        -0.178779,  0.147602,  0.303900, 0.0,
+         0.0,       0.0,       0.0,      1.0,
+    );
+
+    /// Shifts the error of red-green deficiencies into the green and blue
+    /// channels.
+    #[rustfmt::skip]
+    const RED_GREEN_SHIFT: Transform = Transform::new(
+        0.0, 0.7, 0.7, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    );
+
+    /// Shifts the error of blue-yellow deficiencies into the red and green
+    /// channels.
+    #[rustfmt::skip]
+    const BLUE_YELLOW_SHIFT: Transform = Transform::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.7, 0.7, 0.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    );
+
+    fn simulation_matrix(&self) -> &'static Transform {
+        match self {
+            Deficiency::Protan => &Self::PROTAN,
+            Deficiency::Deutan => &Self::DEUTAN,
+            Deficiency::Tritan => &Self::TRITAN,
+        }
+    }
+
+    fn shift_matrix(&self) -> &'static Transform {
+        match self {
+            Deficiency::Protan | Deficiency::Deutan => &Self::RED_GREEN_SHIFT,
+            Deficiency::Tritan => &Self::BLUE_YELLOW_SHIFT,
+        }
+    }
+}
+
+/// Simulate the deficiency on linear-light sRGB components.
+pub(crate) fn simulate_linear(
+    linear: &ColorComponents,
+    deficiency: Deficiency,
+    severity: f32,
+) -> ColorComponents {
+    let severity = severity.clamp(0.0, 1.0);
+    let simulated = transform(linear, deficiency.simulation_matrix());
+
+    ColorComponents(
+        linear.0 + (simulated.0 - linear.0) * severity,
+        linear.1 + (simulated.1 - linear.1) * severity,
+        linear.2 + (simulated.2 - linear.2) * severity,
+    )
+}
+
+impl Color {
+    /// Simulate how this color is perceived by someone with the given color
+    /// vision deficiency. `severity` ranges from 0 (normal vision) to 1
+    /// (dichromacy). The result is in the same color space as this color.
+    pub fn simulate_deficiency(&self, deficiency: Deficiency, severity: f32) -> Self {
+        let linear = self.linear_srgb_components();
+        let simulated = simulate_linear(&linear, deficiency, severity);
+
+        self.with_linear_srgb_components(&simulated)
+    }
+
+    /// Daltonize this color for the given color vision deficiency.
+    ///
+    /// The contrast that is lost when the color is simulated for the
+    /// deficiency is redistributed into channels that remain visible, as
+    /// described by Fidaner, Lin and Ozguven. The result is in the same color
+    /// space as this color and may fall outside of the sRGB gamut.
+    pub fn daltonize(&self, deficiency: Deficiency, severity: f32) -> Self {
+        let linear = self.linear_srgb_components();
+        let simulated = simulate_linear(&linear, deficiency, severity);
+
+        let error = ColorComponents(
+            linear.0 - simulated.0,
+            linear.1 - simulated.1,
+            linear.2 - simulated.2,
+        );
+        let shift = transform(&error, deficiency.shift_matrix());
+
+        let corrected = ColorComponents(linear.0 + shift.0, linear.1 + shift.1, linear.2 + shift.2);
+
+        self.with_linear_srgb_components(&corrected)
+    }
+
+    /// The components of this color converted to linear-light sRGB.
+    pub(crate) fn linear_srgb_components(&self) -> ColorComponents {
+        self.clone()
+            .into_color_space(ColorSpace::SrgbLinear)
+            .components()
+            .clone()
+    }
+
+    /// Create a color in the same color space and with the same alpha as this
+    /// color from linear-light sRGB components.
+    pub(crate) fn with_linear_srgb_components(&self, linear: &ColorComponents) -> Self {
+        Color::new(ColorSpace::SrgbLinear, linear.clone())
+            .with_alpha(self.alpha())
+            .into_color_space(self.color_space())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &ColorComponents, b: &ColorComponents) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3 && (a.2 - b.2).abs() < 1e-3,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn neutral_colors_are_unaffected() {
+        let gray = Color::new(ColorSpace::Srgb, ColorComponents(0.5, 0.5, 0.5));
+
+        for deficiency in [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan] {
+            let simulated = gray.simulate_deficiency(deficiency, 1.0);
+            assert_close(simulated.components(), gray.components());
+
+            let daltonized = gray.daltonize(deficiency, 1.0);
+            assert_close(daltonized.components(), gray.components());
+        }
+    }
+
+    #[test]
+    fn zero_severity_is_identity() {
+        let red = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 0.0, 0.0)).with_alpha(0.5);
+        let result = red.daltonize(Deficiency::Protan, 0.0);
+
+        assert_close(result.components(), red.components());
+        assert_eq!(result.alpha(), 0.5);
+        assert_eq!(result.color_space(), ColorSpace::Srgb);
+    }
+
+    #[test]
+    fn daltonize_keeps_color_space() {
+        let red = Color::new(ColorSpace::Oklch, ColorComponents(0.6, 0.2, 30.0));
+        let result = red.daltonize(Deficiency::Deutan, 1.0);
+
+        assert_eq!(result.color_space(), ColorSpace::Oklch);
+        assert_ne!(result.components(), red.components());
+    }
+}
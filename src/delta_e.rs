@@ -0,0 +1,53 @@
+//! Color difference metrics.
+
+use crate::{Color, ColorComponents, ColorSpace};
+
+impl Color {
+    /// The ΔEOK color difference between this color and `other`, which is the
+    /// euclidean distance between the two colors in Oklab.
+    /// https://w3c.github.io/csswg-drafts/css-color-4/#color-difference-OK
+    pub fn delta_e_ok(&self, other: &Color) -> f32 {
+        let a = self.clone().into_color_space(ColorSpace::Oklab);
+        let b = other.clone().into_color_space(ColorSpace::Oklab);
+
+        distance(a.components(), b.components())
+    }
+
+    /// The ΔEJz color difference between this color and `other`, computed in
+    /// JzCzhz.
+    /// https://doi.org/10.1364/OE.25.015131
+    pub fn delta_e_jz(&self, other: &Color) -> f32 {
+        let a = self.clone().into_color_space(ColorSpace::Jzczhz);
+        let b = other.clone().into_color_space(ColorSpace::Jzczhz);
+        let ColorComponents(j1, c1, h1) = *a.components();
+        let ColorComponents(j2, c2, h2) = *b.components();
+
+        // The hue difference is irrelevant when either color is achromatic.
+        let delta_h = if c1 == 0.0 || c2 == 0.0 {
+            0.0
+        } else {
+            (h2 - h1).to_radians()
+        };
+        let delta_hue = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).sin();
+
+        ((j1 - j2).powi(2) + (c1 - c2).powi(2) + delta_hue.powi(2)).sqrt()
+    }
+
+    /// The ΔEITP color difference between this color and `other`, where a
+    /// difference of 1 is a just noticeable difference.
+    /// https://www.itu.int/rec/R-REC-BT.2124
+    pub fn delta_e_itp(&self, other: &Color) -> f32 {
+        let a = self.clone().into_color_space(ColorSpace::Ictcp);
+        let b = other.clone().into_color_space(ColorSpace::Ictcp);
+        let ColorComponents(i1, ct1, cp1) = *a.components();
+        let ColorComponents(i2, ct2, cp2) = *b.components();
+
+        // T is half of Ct.
+        720.0 * ((i1 - i2).powi(2) + (0.5 * (ct1 - ct2)).powi(2) + (cp1 - cp2).powi(2)).sqrt()
+    }
+}
+
+/// The euclidean distance between two sets of rectangular components.
+pub(crate) fn distance(a: &ColorComponents, b: &ColorComponents) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)).sqrt()
+}
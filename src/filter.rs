@@ -0,0 +1,210 @@
+//! Filter functions from the [Filter Effects specification](https://drafts.fxtf.org/filter-effects/#filter-functions).
+
+use crate::filter_primitive::{ColorMatrix, FeColorMatrix, FeComponentTransfer, TransferFunction};
+use crate::{Color, ColorComponents, ColorSpace};
+
+/// The color space filter operations are performed in.
+/// https://drafts.fxtf.org/filter-effects/#propdef-color-interpolation-filters
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ColorInterpolationFilters {
+    /// Operate on gamma-encoded sRGB values.
+    Srgb,
+    /// Operate on linear-light sRGB values. This is the initial value of the
+    /// property for SVG filter primitives. The CSS filter functions always
+    /// operate in sRGB.
+    #[default]
+    LinearRgb,
+}
+
+impl ColorInterpolationFilters {
+    fn color_space(&self) -> ColorSpace {
+        match self {
+            ColorInterpolationFilters::Srgb => ColorSpace::Srgb,
+            ColorInterpolationFilters::LinearRgb => ColorSpace::SrgbLinear,
+        }
+    }
+}
+
+/// A filter function that can be applied to a color. Amounts are specified as
+/// numbers where 1.0 is 100% and angles are in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FilterFunction {
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-grayscale
+    Grayscale(f32),
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-sepia
+    Sepia(f32),
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-saturate
+    Saturate(f32),
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-hue-rotate
+    HueRotate(f32),
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-invert
+    Invert(f32),
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-opacity
+    Opacity(f32),
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-brightness
+    Brightness(f32),
+    /// https://drafts.fxtf.org/filter-effects/#funcdef-filter-contrast
+    Contrast(f32),
+}
+
+/// Red, green, blue and straight alpha values in the filter color space.
+pub(crate) type Rgba = [f32; 4];
+
+impl FilterFunction {
+    /// Apply the filter function using the filter primitives it is defined
+    /// as being equivalent to.
+    /// https://drafts.fxtf.org/filter-effects/#ShorthandEquivalents
+    fn apply(&self, rgba: Rgba) -> Rgba {
+        match *self {
+            FilterFunction::Grayscale(amount) => {
+                FeColorMatrix::Matrix(grayscale_matrix(amount.clamp(0.0, 1.0))).apply(rgba)
+            }
+            FilterFunction::Sepia(amount) => {
+                FeColorMatrix::Matrix(sepia_matrix(amount.clamp(0.0, 1.0))).apply(rgba)
+            }
+            FilterFunction::Saturate(amount) => {
+                FeColorMatrix::Saturate(amount.max(0.0)).apply(rgba)
+            }
+            FilterFunction::HueRotate(angle) => FeColorMatrix::HueRotate(angle).apply(rgba),
+            FilterFunction::Invert(amount) => {
+                let amount = amount.clamp(0.0, 1.0);
+                FeComponentTransfer::rgb(TransferFunction::Table(vec![amount, 1.0 - amount]))
+                    .apply(rgba)
+            }
+            FilterFunction::Opacity(amount) => FeComponentTransfer {
+                alpha: TransferFunction::Table(vec![0.0, amount.clamp(0.0, 1.0)]),
+                ..Default::default()
+            }
+            .apply(rgba),
+            FilterFunction::Brightness(amount) => {
+                FeComponentTransfer::rgb(TransferFunction::Linear {
+                    slope: amount.max(0.0),
+                    intercept: 0.0,
+                })
+                .apply(rgba)
+            }
+            FilterFunction::Contrast(amount) => {
+                let slope = amount.max(0.0);
+                FeComponentTransfer::rgb(TransferFunction::Linear {
+                    slope,
+                    intercept: -(0.5 * slope) + 0.5,
+                })
+                .apply(rgba)
+            }
+        }
+    }
+}
+
+impl Color {
+    /// Apply the list of filter functions to this color in order. Like
+    /// browsers, the functions operate on gamma-encoded sRGB values,
+    /// regardless of `color-interpolation-filters`. The result is in the same
+    /// color space as this color.
+    pub fn filter(&self, functions: &[FilterFunction]) -> Self {
+        let space = ColorInterpolationFilters::Srgb;
+        let rgba = functions
+            .iter()
+            .fold(self.to_filter_rgba(space), |rgba, function| {
+                function.apply(rgba)
+            });
+
+        Color::from_filter_rgba(rgba, space).into_color_space(self.color_space())
+    }
+
+    /// The components and alpha of this color in the filter color space,
+    /// clamped to [0 - 1].
+    pub(crate) fn to_filter_rgba(&self, space: ColorInterpolationFilters) -> Rgba {
+        let color = self.clone().into_color_space(space.color_space());
+        let ColorComponents(r, g, b) = color.components().clone();
+        [r, g, b, color.alpha()].map(|value| value.clamp(0.0, 1.0))
+    }
+
+    /// Create a color from components and alpha in the filter color space.
+    pub(crate) fn from_filter_rgba(rgba: Rgba, space: ColorInterpolationFilters) -> Self {
+        let [r, g, b, a] = rgba;
+        Color::new(space.color_space(), ColorComponents(r, g, b)).with_alpha(a)
+    }
+}
+
+#[rustfmt::skip]
+fn grayscale_matrix(amount: f32) -> ColorMatrix {
+    let inv = 1.0 - amount;
+    [
+        0.2126 + 0.7874 * inv, 0.7152 - 0.7152 * inv, 0.0722 - 0.0722 * inv, 0.0, 0.0,
+        0.2126 - 0.2126 * inv, 0.7152 + 0.2848 * inv, 0.0722 - 0.0722 * inv, 0.0, 0.0,
+        0.2126 - 0.2126 * inv, 0.7152 - 0.7152 * inv, 0.0722 + 0.9278 * inv, 0.0, 0.0,
+        0.0,                   0.0,                   0.0,                   1.0, 0.0,
+    ]
+}
+
+#[rustfmt::skip]
+fn sepia_matrix(amount: f32) -> ColorMatrix {
+    let inv = 1.0 - amount;
+    [
+        0.393 + 0.607 * inv, 0.769 - 0.769 * inv, 0.189 - 0.189 * inv, 0.0, 0.0,
+        0.349 - 0.349 * inv, 0.686 + 0.314 * inv, 0.168 - 0.168 * inv, 0.0, 0.0,
+        0.272 - 0.272 * inv, 0.534 - 0.534 * inv, 0.131 + 0.869 * inv, 0.0, 0.0,
+        0.0,                 0.0,                 0.0,                 1.0, 0.0,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn srgb(r: f32, g: f32, b: f32) -> Color {
+        Color::new(ColorSpace::Srgb, ColorComponents(r, g, b))
+    }
+
+    fn assert_rgba(color: &Color, expected: Rgba) {
+        let ColorComponents(r, g, b) = color.components().clone();
+        let actual = [r, g, b, color.alpha()];
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-3, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn filter_functions() {
+        let color = srgb(0.2, 0.4, 0.6).with_alpha(0.8);
+
+        let expected = [
+            (
+                FilterFunction::Grayscale(1.0),
+                [0.3719, 0.3719, 0.3719, 0.8],
+            ),
+            (FilterFunction::Sepia(0.0), [0.2, 0.4, 0.6, 0.8]),
+            (FilterFunction::Saturate(1.0), [0.2, 0.4, 0.6, 0.8]),
+            (FilterFunction::HueRotate(0.0), [0.2, 0.4, 0.6, 0.8]),
+            (FilterFunction::Invert(1.0), [0.8, 0.6, 0.4, 0.8]),
+            (FilterFunction::Invert(0.5), [0.5, 0.5, 0.5, 0.8]),
+            (FilterFunction::Opacity(0.5), [0.2, 0.4, 0.6, 0.4]),
+            (FilterFunction::Brightness(2.0), [0.4, 0.8, 1.0, 0.8]),
+            (FilterFunction::Contrast(0.0), [0.5, 0.5, 0.5, 0.8]),
+        ];
+
+        for (function, rgba) in expected {
+            assert_rgba(&color.filter(&[function]), rgba);
+        }
+    }
+
+    #[test]
+    fn filter_returns_original_color_space() {
+        let color = srgb(0.2, 0.4, 0.6).into_color_space(ColorSpace::Oklch);
+        let result = color.filter(&[FilterFunction::Invert(1.0)]);
+
+        assert_eq!(result.color_space(), ColorSpace::Oklch);
+        assert_rgba(
+            &result.into_color_space(ColorSpace::Srgb),
+            [0.8, 0.6, 0.4, 1.0],
+        );
+    }
+
+    #[test]
+    fn linear_rgb_is_the_default() {
+        assert_eq!(
+            ColorInterpolationFilters::default(),
+            ColorInterpolationFilters::LinearRgb
+        );
+    }
+}
@@ -0,0 +1,260 @@
+//! Evaluation of the SVG `feColorMatrix` and `feComponentTransfer` filter
+//! primitives from the [Filter Effects specification](https://drafts.fxtf.org/filter-effects/).
+
+use crate::filter::{ColorInterpolationFilters, Rgba};
+use crate::Color;
+
+/// A 5x4 color matrix in row-major order, as used by `feColorMatrix`.
+pub type ColorMatrix = [f32; 20];
+
+/// The `feColorMatrix` filter primitive.
+/// https://drafts.fxtf.org/filter-effects/#feColorMatrixElement
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FeColorMatrix {
+    /// A full 5x4 matrix in row-major order.
+    Matrix(ColorMatrix),
+    /// Saturate the color by the given amount, where 0 is fully desaturated
+    /// and 1 leaves the color unchanged.
+    Saturate(f32),
+    /// Rotate the hue by the given number of degrees.
+    HueRotate(f32),
+    /// Convert the luminance of the color to alpha, leaving the color black.
+    LuminanceToAlpha,
+}
+
+impl FeColorMatrix {
+    /// The 5x4 matrix this primitive multiplies colors with.
+    pub fn matrix(&self) -> ColorMatrix {
+        match *self {
+            FeColorMatrix::Matrix(matrix) => matrix,
+            FeColorMatrix::Saturate(s) => saturate_matrix(s),
+            FeColorMatrix::HueRotate(degrees) => hue_rotate_matrix(degrees),
+            FeColorMatrix::LuminanceToAlpha => luminance_to_alpha_matrix(),
+        }
+    }
+
+    pub(crate) fn apply(&self, rgba: Rgba) -> Rgba {
+        let matrix = self.matrix();
+
+        let mut result = [0.0; 4];
+        for (row, value) in result.iter_mut().enumerate() {
+            let m = &matrix[row * 5..row * 5 + 5];
+            *value = (m[0] * rgba[0] + m[1] * rgba[1] + m[2] * rgba[2] + m[3] * rgba[3] + m[4])
+                .clamp(0.0, 1.0);
+        }
+        result
+    }
+}
+
+/// A transfer function applied to a single channel by `feComponentTransfer`.
+/// https://drafts.fxtf.org/filter-effects/#transfer-functions
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum TransferFunction {
+    /// Leave the channel unchanged.
+    #[default]
+    Identity,
+    /// Linearly interpolate between the values in the table. An empty table
+    /// leaves the channel unchanged.
+    Table(Vec<f32>),
+    /// Step function through the values in the table. An empty table leaves
+    /// the channel unchanged.
+    Discrete(Vec<f32>),
+    /// `slope * C + intercept`
+    Linear { slope: f32, intercept: f32 },
+    /// `amplitude * pow(C, exponent) + offset`
+    Gamma {
+        amplitude: f32,
+        exponent: f32,
+        offset: f32,
+    },
+}
+
+impl TransferFunction {
+    /// Evaluate the transfer function for a single channel value.
+    pub fn evaluate(&self, value: f32) -> f32 {
+        let result = match self {
+            TransferFunction::Identity => value,
+            TransferFunction::Table(table) if table.is_empty() => value,
+            TransferFunction::Table(table) => {
+                let n = table.len() - 1;
+                if n == 0 {
+                    table[0]
+                } else {
+                    let k = ((value * n as f32).floor().max(0.0) as usize).min(n - 1);
+                    let (vk, vk1) = (table[k], table[k + 1]);
+                    vk + (value - k as f32 / n as f32) * n as f32 * (vk1 - vk)
+                }
+            }
+            TransferFunction::Discrete(table) if table.is_empty() => value,
+            TransferFunction::Discrete(table) => {
+                let n = table.len();
+                let k = ((value * n as f32).floor().max(0.0) as usize).min(n - 1);
+                table[k]
+            }
+            TransferFunction::Linear { slope, intercept } => slope * value + intercept,
+            TransferFunction::Gamma {
+                amplitude,
+                exponent,
+                offset,
+            } => amplitude * value.powf(*exponent) + offset,
+        };
+
+        result.clamp(0.0, 1.0)
+    }
+}
+
+/// The `feComponentTransfer` filter primitive, with a transfer function for
+/// each channel.
+/// https://drafts.fxtf.org/filter-effects/#feComponentTransferElement
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeComponentTransfer {
+    pub red: TransferFunction,
+    pub green: TransferFunction,
+    pub blue: TransferFunction,
+    pub alpha: TransferFunction,
+}
+
+impl FeComponentTransfer {
+    /// Apply the same transfer function to the red, green and blue channels,
+    /// leaving alpha unchanged.
+    pub fn rgb(function: TransferFunction) -> Self {
+        Self {
+            red: function.clone(),
+            green: function.clone(),
+            blue: function,
+            alpha: TransferFunction::Identity,
+        }
+    }
+
+    pub(crate) fn apply(&self, rgba: Rgba) -> Rgba {
+        let [r, g, b, a] = rgba;
+        [
+            self.red.evaluate(r),
+            self.green.evaluate(g),
+            self.blue.evaluate(b),
+            self.alpha.evaluate(a),
+        ]
+    }
+}
+
+impl Color {
+    /// Evaluate the `feColorMatrix` primitive on this color, operating in the
+    /// given color space. The result is in the same color space as this
+    /// color.
+    pub fn color_matrix(
+        &self,
+        primitive: &FeColorMatrix,
+        space: ColorInterpolationFilters,
+    ) -> Self {
+        let rgba = primitive.apply(self.to_filter_rgba(space));
+        Color::from_filter_rgba(rgba, space).into_color_space(self.color_space())
+    }
+
+    /// Evaluate the `feComponentTransfer` primitive on this color, operating
+    /// in the given color space. The result is in the same color space as
+    /// this color.
+    pub fn component_transfer(
+        &self,
+        primitive: &FeComponentTransfer,
+        space: ColorInterpolationFilters,
+    ) -> Self {
+        let rgba = primitive.apply(self.to_filter_rgba(space));
+        Color::from_filter_rgba(rgba, space).into_color_space(self.color_space())
+    }
+}
+
+#[rustfmt::skip]
+fn saturate_matrix(s: f32) -> ColorMatrix {
+    [
+        0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s, 0.0, 0.0,
+        0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s, 0.0, 0.0,
+        0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s, 0.0, 0.0,
+        0.0,               0.0,               0.0,               1.0, 0.0,
+    ]
+}
+
+#[rustfmt::skip]
+fn hue_rotate_matrix(degrees: f32) -> ColorMatrix {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    [
+        0.213 + cos * 0.787 - sin * 0.213,
+        0.715 - cos * 0.715 - sin * 0.715,
+        0.072 - cos * 0.072 + sin * 0.928,
+        0.0, 0.0,
+
+        0.213 - cos * 0.213 + sin * 0.143,
+        0.715 + cos * 0.285 + sin * 0.140,
+        0.072 - cos * 0.072 - sin * 0.283,
+        0.0, 0.0,
+
+        0.213 - cos * 0.213 - sin * 0.787,
+        0.715 - cos * 0.715 + sin * 0.715,
+        0.072 + cos * 0.928 + sin * 0.072,
+        0.0, 0.0,
+
+        0.0, 0.0, 0.0, 1.0, 0.0,
+    ]
+}
+
+#[rustfmt::skip]
+fn luminance_to_alpha_matrix() -> ColorMatrix {
+    [
+        0.0,    0.0,    0.0,    0.0, 0.0,
+        0.0,    0.0,    0.0,    0.0, 0.0,
+        0.0,    0.0,    0.0,    0.0, 0.0,
+        0.2125, 0.7154, 0.0721, 0.0, 0.0,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer_functions() {
+        let expected = [
+            (TransferFunction::Identity, 0.3, 0.3),
+            (TransferFunction::Table(vec![]), 0.3, 0.3),
+            (TransferFunction::Table(vec![1.0, 0.0]), 0.3, 0.7),
+            (TransferFunction::Table(vec![0.0, 0.5, 1.0]), 0.75, 0.75),
+            (TransferFunction::Discrete(vec![0.0, 0.5, 1.0]), 0.5, 0.5),
+            (TransferFunction::Discrete(vec![0.0, 0.5, 1.0]), 1.0, 1.0),
+            (
+                TransferFunction::Linear {
+                    slope: 2.0,
+                    intercept: 0.1,
+                },
+                0.3,
+                0.7,
+            ),
+            (
+                TransferFunction::Gamma {
+                    amplitude: 1.0,
+                    exponent: 2.0,
+                    offset: 0.0,
+                },
+                0.5,
+                0.25,
+            ),
+        ];
+
+        for (function, input, output) in expected {
+            assert!(
+                (function.evaluate(input) - output).abs() < 1e-5,
+                "{:?}({}) != {}",
+                function,
+                input,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn luminance_to_alpha() {
+        let white = [1.0, 1.0, 1.0, 1.0];
+        let result = FeColorMatrix::LuminanceToAlpha.apply(white);
+
+        assert_eq!(&result[..3], &[0.0, 0.0, 0.0]);
+        assert!((result[3] - 1.0).abs() < 1e-3);
+    }
+}
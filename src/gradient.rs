@@ -0,0 +1,339 @@
+//! Color stop resolution and sampling of gradients as specified by
+//! [CSS Images](https://w3c.github.io/csswg-drafts/css-images-4/#color-stop-syntax).
+
+use crate::{Color, ColorSpace, HueInterpolationMethod};
+use std::sync::OnceLock;
+
+/// An entry in the list of color stops of a gradient.
+#[derive(Clone, Debug)]
+pub enum GradientItem {
+    /// A color stop with an optional position along the gradient line, where
+    /// 0 is the start and 1 is the end of the line.
+    ColorStop { color: Color, position: Option<f32> },
+    /// A transition hint that moves the midpoint of the transition between
+    /// the two surrounding color stops to the given position.
+    Hint(f32),
+}
+
+/// A color stop with its position resolved.
+#[derive(Clone, Debug)]
+struct ResolvedStop {
+    color: Color,
+    position: f32,
+    /// The position of the transition hint between this stop and the next.
+    hint: Option<f32>,
+}
+
+/// A list of color stops and transition hints that can be sampled at any
+/// offset along the gradient line.
+#[derive(Clone, Debug)]
+pub struct Gradient {
+    items: Vec<GradientItem>,
+    color_space: ColorSpace,
+    hue_interpolation: HueInterpolationMethod,
+    /// The resolved stops, computed the first time they are needed.
+    stops: OnceLock<Vec<ResolvedStop>>,
+}
+
+impl Gradient {
+    /// Create an empty gradient that interpolates in the given color space.
+    pub fn new(color_space: ColorSpace) -> Self {
+        Self {
+            items: vec![],
+            color_space,
+            hue_interpolation: HueInterpolationMethod::default(),
+            stops: OnceLock::new(),
+        }
+    }
+
+    /// Set the method used to interpolate hues in polar color spaces.
+    pub fn with_hue_interpolation(mut self, hue_interpolation: HueInterpolationMethod) -> Self {
+        self.hue_interpolation = hue_interpolation;
+        self
+    }
+
+    /// Add a color stop. When `position` is `None` or NaN it is resolved
+    /// according to the positions of the surrounding stops.
+    pub fn with_stop(mut self, color: Color, position: Option<f32>) -> Self {
+        self.items.push(GradientItem::ColorStop { color, position });
+        self.stops = OnceLock::new();
+        self
+    }
+
+    /// Add a transition hint after the last color stop. A NaN hint is
+    /// ignored.
+    pub fn with_hint(mut self, position: f32) -> Self {
+        self.items.push(GradientItem::Hint(position));
+        self.stops = OnceLock::new();
+        self
+    }
+
+    /// The color stops and hints as they were specified.
+    pub fn items(&self) -> &[GradientItem] {
+        &self.items
+    }
+
+    /// The color space colors are interpolated in.
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// The resolved position of each color stop.
+    pub fn stop_positions(&self) -> Vec<f32> {
+        self.stops().iter().map(|stop| stop.position).collect()
+    }
+
+    fn stops(&self) -> &[ResolvedStop] {
+        self.stops
+            .get_or_init(|| resolve(&self.items, self.color_space))
+    }
+
+    /// The color of the gradient at the given offset along the gradient line.
+    /// The result is in the interpolation color space of the gradient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the gradient has no color stops.
+    pub fn sample(&self, offset: f32) -> Color {
+        // Treat an undefined offset as the start of the gradient line.
+        let offset = if offset.is_nan() { 0.0 } else { offset };
+
+        let stops = self.stops();
+        let first = stops.first().expect("gradient has no color stops");
+        let last = stops.last().unwrap();
+
+        if offset <= first.position {
+            return first.color.clone();
+        }
+        if offset >= last.position {
+            return last.color.clone();
+        }
+
+        // Find the last stop at or before the offset. Stops at the same
+        // position create a hard transition, so the later one wins.
+        let index = stops
+            .iter()
+            .rposition(|stop| stop.position <= offset)
+            .unwrap();
+        let from = &stops[index];
+        let to = &stops[index + 1];
+
+        let length = to.position - from.position;
+        if length <= 0.0 {
+            return to.color.clone();
+        }
+
+        let mut progress = (offset - from.position) / length;
+        if let Some(hint) = from.hint {
+            progress = apply_hint(progress, (hint - from.position) / length);
+        }
+
+        from.color.interpolate(
+            &to.color,
+            progress,
+            self.color_space,
+            self.hue_interpolation,
+        )
+    }
+}
+
+/// Adjust the progress between two stops for a transition hint at the
+/// relative position `hint`.
+/// https://w3c.github.io/csswg-drafts/css-images-4/#coloring-gradient-line
+fn apply_hint(progress: f32, hint: f32) -> f32 {
+    if hint <= 0.0 {
+        1.0
+    } else if hint >= 1.0 {
+        0.0
+    } else {
+        progress.powf(0.5_f32.ln() / hint.ln())
+    }
+}
+
+/// Resolve the positions of all color stops and hints.
+/// https://w3c.github.io/csswg-drafts/css-images-3/#color-stop-fixup
+fn resolve(items: &[GradientItem], color_space: ColorSpace) -> Vec<ResolvedStop> {
+    // Hints are only valid between two color stops. Undefined positions are
+    // treated as missing.
+    let mut entries: Vec<(Option<Color>, Option<f32>)> = vec![];
+    for item in items {
+        match item {
+            GradientItem::ColorStop { color, position } => {
+                let position = position.filter(|position| !position.is_nan());
+                entries.push((Some(color.clone()), position));
+            }
+            GradientItem::Hint(position) => {
+                if !position.is_nan() && matches!(entries.last(), Some((Some(_), _))) {
+                    entries.push((None, Some(*position)));
+                }
+            }
+        }
+    }
+    if matches!(entries.last(), Some((None, _))) {
+        entries.pop();
+    }
+
+    let stop_count = entries.iter().filter(|(color, _)| color.is_some()).count();
+    if stop_count == 0 {
+        return vec![];
+    }
+
+    // 1. If the first or last color stop has no position, set it to 0% or
+    //    100% respectively.
+    if entries[0].1.is_none() {
+        entries[0].1 = Some(0.0);
+    }
+    let last = entries.len() - 1;
+    if entries[last].1.is_none() {
+        entries[last].1 = Some(if stop_count == 1 { 0.0 } else { 1.0 });
+    }
+
+    // 2. If a color stop or hint has a position that is less than any
+    //    position before it, set it to the largest position before it.
+    let mut largest = f32::NEG_INFINITY;
+    for (_, position) in entries.iter_mut() {
+        if let Some(position) = position {
+            *position = position.max(largest);
+            largest = *position;
+        }
+    }
+
+    // 3. Space runs of color stops without a position evenly between the
+    //    positioned color stops around them. Hints are not color stops, so
+    //    they don't break up a run.
+    let color_stops: Vec<usize> = (0..entries.len())
+        .filter(|i| entries[*i].0.is_some())
+        .collect();
+    let mut index = 0;
+    while index < color_stops.len() {
+        if entries[color_stops[index]].1.is_some() {
+            index += 1;
+            continue;
+        }
+
+        let start = index - 1;
+        let end = (index..color_stops.len())
+            .find(|i| entries[color_stops[*i]].1.is_some())
+            .unwrap();
+        let from = entries[color_stops[start]].1.unwrap();
+        let to = entries[color_stops[end]].1.unwrap();
+        let count = (end - start) as f32;
+        for (step, entry) in color_stops[index..end].iter().enumerate() {
+            entries[*entry].1 = Some(from + (to - from) * (step + 1) as f32 / count);
+        }
+        index = end;
+    }
+
+    let mut stops: Vec<ResolvedStop> = vec![];
+    for (color, position) in entries {
+        let position = position.unwrap();
+        match color {
+            Some(color) => stops.push(ResolvedStop {
+                color: color.into_color_space(color_space),
+                position,
+                hint: None,
+            }),
+            None => stops.last_mut().unwrap().hint = Some(position),
+        }
+    }
+    stops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorComponents;
+
+    fn srgb(r: f32, g: f32, b: f32) -> Color {
+        Color::new(ColorSpace::Srgb, ColorComponents(r, g, b))
+    }
+
+    #[test]
+    fn resolve_missing_positions() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(srgb(1.0, 0.0, 0.0), None)
+            .with_stop(srgb(0.0, 1.0, 0.0), None)
+            .with_stop(srgb(0.0, 0.0, 1.0), Some(0.8))
+            .with_stop(srgb(1.0, 1.0, 1.0), None);
+
+        assert_eq!(gradient.stop_positions(), vec![0.0, 0.4, 0.8, 1.0]);
+    }
+
+    #[test]
+    fn resolve_decreasing_positions() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(srgb(1.0, 0.0, 0.0), Some(0.5))
+            .with_stop(srgb(0.0, 1.0, 0.0), Some(0.2))
+            .with_stop(srgb(0.0, 0.0, 1.0), None);
+
+        assert_eq!(gradient.stop_positions(), vec![0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn resolve_missing_positions_around_hint() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(srgb(1.0, 0.0, 0.0), None)
+            .with_hint(0.3)
+            .with_stop(srgb(0.0, 0.0, 1.0), None)
+            .with_stop(srgb(0.0, 1.0, 0.0), None);
+
+        assert_eq!(gradient.stop_positions(), vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn sample_nan() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(srgb(0.0, 0.0, 0.0), Some(-0.5))
+            .with_stop(srgb(1.0, 1.0, 1.0), Some(1.0));
+
+        assert_eq!(
+            gradient.sample(f32::NAN).components(),
+            gradient.sample(0.0).components()
+        );
+    }
+
+    #[test]
+    fn resolve_nan_positions() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(srgb(1.0, 0.0, 0.0), Some(f32::NAN))
+            .with_hint(f32::NAN)
+            .with_stop(srgb(0.0, 1.0, 0.0), Some(f32::NAN))
+            .with_stop(srgb(0.0, 0.0, 1.0), Some(f32::NAN));
+
+        assert_eq!(gradient.stop_positions(), vec![0.0, 0.5, 1.0]);
+        assert!((gradient.sample(0.25).components().0 - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn sample_with_hint() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(srgb(0.0, 0.0, 0.0), None)
+            .with_hint(0.25)
+            .with_stop(srgb(1.0, 1.0, 1.0), None);
+
+        // The midpoint of the transition is moved to the hint.
+        let color = gradient.sample(0.25);
+        assert!((color.components().0 - 0.5).abs() < 1e-5);
+
+        assert_eq!(gradient.sample(-1.0).components().0, 0.0);
+        assert_eq!(gradient.sample(2.0).components().0, 1.0);
+    }
+
+    #[test]
+    fn sample_hard_stop() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(srgb(1.0, 0.0, 0.0), Some(0.0))
+            .with_stop(srgb(1.0, 0.0, 0.0), Some(0.5))
+            .with_stop(srgb(0.0, 0.0, 1.0), Some(0.5))
+            .with_stop(srgb(0.0, 0.0, 1.0), Some(1.0));
+
+        assert_eq!(
+            gradient.sample(0.49).components(),
+            &ColorComponents(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            gradient.sample(0.5).components(),
+            &ColorComponents(0.0, 0.0, 1.0)
+        );
+    }
+}
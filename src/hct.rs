@@ -0,0 +1,672 @@
+//! The HCT color space (CAM16 hue and chroma with CIE L* tone) and the
+//! Material 3 tonal palettes and color schemes built on it.
+//! https://m3.material.io/styles/color/system/how-the-system-works
+
+use crate::cam16::{Cam16, ViewingConditions};
+use crate::{Color, ColorComponents, ColorSpace};
+use std::sync::OnceLock;
+
+/// Below this chroma a color is treated as a neutral gray.
+const MIN_CHROMA: f32 = 0.000_1;
+
+/// The viewing conditions of HCT, which are the default CAM16 conditions with
+/// the D65 white point of Material Color Utilities.
+pub(crate) fn viewing_conditions() -> &'static ViewingConditions {
+    static CONDITIONS: OnceLock<ViewingConditions> = OnceLock::new();
+    CONDITIONS.get_or_init(|| ViewingConditions::srgb_display(solver::WHITE_POINT))
+}
+
+/// Convert HCT components to XYZ with a D65 white point, without mapping the
+/// result into any gamut. Colors inside the sRGB gamut are found with the
+/// solver, so they match [Color::from_hct].
+pub(crate) fn hct_to_xyz(hct: &ColorComponents) -> ColorComponents {
+    const ITERATIONS: usize = 32;
+
+    let ColorComponents(hue, chroma, tone) = *hct;
+    let (hue, chroma, tone) = (hue as f64, chroma as f64, tone as f64);
+    let luminance = solver::y_from_lstar(tone) / 100.0;
+    let xyz = if chroma < MIN_CHROMA as f64 || luminance <= 0.0 {
+        solver::WHITE_POINT.map(|w| w * luminance)
+    } else if let Some(linear) = solver::find_in_gamut(hue, chroma, tone) {
+        solver::linear_to_xyz(linear)
+    } else {
+        // Luminance increases with CAM16 lightness, so search for the
+        // lightness that gives the luminance of the tone.
+        let conditions = viewing_conditions();
+        let hue = hue.rem_euclid(360.0).to_radians();
+        let (mut low, mut high) = (0.0, 200.0);
+        let mut xyz = [0.0; 3];
+        for _ in 0..ITERATIONS {
+            let lightness = (low + high) / 2.0;
+            xyz = Cam16::jch_to_xyz_f64(lightness, chroma, hue, conditions);
+            if xyz[1] < luminance {
+                low = lightness;
+            } else {
+                high = lightness;
+            }
+        }
+        xyz
+    };
+    ColorComponents(xyz[0] as f32, xyz[1] as f32, xyz[2] as f32)
+}
+
+impl Color {
+    /// Find the sRGB color with the given HCT hue and tone and the chroma
+    /// closest to the requested chroma. When the requested chroma is outside
+    /// the sRGB gamut, the most chromatic in-gamut color is returned. The
+    /// components are rounded to 8 bits, to match Material Color Utilities.
+    pub fn from_hct(hue: f32, chroma: f32, tone: f32) -> Color {
+        let linear = solver::solve(hue as f64, chroma.max(0.0) as f64, tone as f64);
+        let [r, g, b] = solver::delinearized(linear);
+        Color::new(ColorSpace::Srgb, ColorComponents(r, g, b))
+    }
+}
+
+/// A port of the HCT solver of Material Color Utilities, which finds the sRGB
+/// color for HCT components exactly like the Material 3 tools do. It works in
+/// double precision with the MCU constants, since single precision round-off
+/// near the sRGB gamut boundary changes the result by a code value.
+/// https://github.com/material-foundation/material-color-utilities/blob/main/typescript/hct/hct_solver.ts
+mod solver {
+    use super::viewing_conditions;
+    use crate::cam16::{adapt, Cam16, XYZ_TO_CAM16_RGB};
+    use crate::white_point::{invert, multiply, multiply_vector, Matrix};
+    use std::f64::consts::PI;
+    use std::sync::OnceLock;
+
+    /// The sRGB to XYZ matrix used by Material Color Utilities.
+    const SRGB_TO_XYZ: Matrix = [
+        [0.41233895, 0.35762064, 0.18051042],
+        [0.2126, 0.7152, 0.0722],
+        [0.01932141, 0.11916382, 0.95034478],
+    ];
+
+    /// The D65 white point used by Material Color Utilities.
+    pub(super) const WHITE_POINT: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+    /// The matrices between linear sRGB in [0 - 100] and CAM16 RGB discounted
+    /// by the degree of adaptation.
+    struct Matrices {
+        discount_from_linear: Matrix,
+        linear_from_discount: Matrix,
+    }
+
+    fn matrices() -> &'static Matrices {
+        static MATRICES: OnceLock<Matrices> = OnceLock::new();
+        MATRICES.get_or_init(|| {
+            let rgb_d = viewing_conditions().rgb_d;
+            let mut discount = [[0.0; 3]; 3];
+            for i in 0..3 {
+                discount[i][i] = rgb_d[i];
+            }
+            let discount_from_linear =
+                multiply(&discount, &multiply(&XYZ_TO_CAM16_RGB, &SRGB_TO_XYZ));
+            Matrices {
+                discount_from_linear,
+                linear_from_discount: invert(&discount_from_linear),
+            }
+        })
+    }
+
+    /// Convert linear sRGB in [0 - 100] to XYZ with a luminance of 1.
+    pub(super) fn linear_to_xyz(linear: [f64; 3]) -> [f64; 3] {
+        multiply_vector(&SRGB_TO_XYZ, linear).map(|v| v / 100.0)
+    }
+
+    /// The luminance in [0 - 100] of CIE L*.
+    pub(super) fn y_from_lstar(lstar: f64) -> f64 {
+        let ft = (lstar + 16.0) / 116.0;
+        let ft3 = ft * ft * ft;
+        if ft3 > 216.0 / 24389.0 {
+            ft3 * 100.0
+        } else {
+            (116.0 * ft - 16.0) / (24389.0 / 27.0) * 100.0
+        }
+    }
+
+    /// Linearize an sRGB component in [0 - 1] to [0 - 100].
+    fn linearized(component: f64) -> f64 {
+        if component <= 0.040449936 {
+            component / 12.92 * 100.0
+        } else {
+            ((component + 0.055) / 1.055).powf(2.4) * 100.0
+        }
+    }
+
+    /// Encode a linear component in [0 - 100] to an sRGB component in
+    /// [0 - 255], without rounding.
+    fn true_delinearized(component: f64) -> f64 {
+        let normalized = component / 100.0;
+        let delinearized = if normalized <= 0.0031308 {
+            normalized * 12.92
+        } else {
+            1.055 * normalized.powf(1.0 / 2.4) - 0.055
+        };
+        delinearized * 255.0
+    }
+
+    /// Encode linear sRGB in [0 - 100] to sRGB in [0 - 1], rounded to 8 bits
+    /// like the ARGB integers of Material Color Utilities.
+    pub(super) fn delinearized(linear: [f64; 3]) -> [f32; 3] {
+        linear.map(|c| (true_delinearized(c).round().clamp(0.0, 255.0) / 255.0) as f32)
+    }
+
+    /// The CAM16 hue in radians of linear sRGB.
+    fn hue_of(linear: [f64; 3]) -> f64 {
+        let fl = viewing_conditions().fl;
+        let discounted = multiply_vector(&matrices().discount_from_linear, linear);
+        let [r, g, b] = discounted.map(|c| adapt(c, fl));
+        let a = (11.0 * r - 12.0 * g + b) / 11.0;
+        let b = (r + g - 2.0 * b) / 9.0;
+        b.atan2(a)
+    }
+
+    fn are_in_cyclic_order(a: f64, b: f64, c: f64) -> bool {
+        let delta_ab = (b - a).rem_euclid(2.0 * PI);
+        let delta_ac = (c - a).rem_euclid(2.0 * PI);
+        delta_ab < delta_ac
+    }
+
+    fn intercept(source: f64, mid: f64, target: f64) -> f64 {
+        (mid - source) / (target - source)
+    }
+
+    fn lerp_point(source: [f64; 3], t: f64, target: [f64; 3]) -> [f64; 3] {
+        [0, 1, 2].map(|i| source[i] + (target[i] - source[i]) * t)
+    }
+
+    fn set_coordinate(
+        source: [f64; 3],
+        coordinate: f64,
+        target: [f64; 3],
+        axis: usize,
+    ) -> [f64; 3] {
+        let t = intercept(source[axis], coordinate, target[axis]);
+        lerp_point(source, t, target)
+    }
+
+    fn is_bounded(x: f64) -> bool {
+        (0.0..=100.0).contains(&x)
+    }
+
+    /// The nth possible vertex of the polygon where the plane of luminance
+    /// `y` intersects the RGB cube, if it lies inside the cube.
+    fn nth_vertex(y: f64, n: usize) -> Option<[f64; 3]> {
+        let [kr, kg, kb] = SRGB_TO_XYZ[1];
+        let coord_a = if n % 4 <= 1 { 0.0 } else { 100.0 };
+        let coord_b = if n.is_multiple_of(2) { 0.0 } else { 100.0 };
+        let vertex = if n < 4 {
+            let (g, b) = (coord_a, coord_b);
+            [(y - g * kg - b * kb) / kr, g, b]
+        } else if n < 8 {
+            let (b, r) = (coord_a, coord_b);
+            [r, (y - r * kr - b * kb) / kg, b]
+        } else {
+            let (r, g) = (coord_a, coord_b);
+            [r, g, (y - r * kr - g * kg) / kb]
+        };
+        let free = if n < 4 {
+            vertex[0]
+        } else if n < 8 {
+            vertex[1]
+        } else {
+            vertex[2]
+        };
+        is_bounded(free).then_some(vertex)
+    }
+
+    /// The edge of the luminance plane polygon that the hue crosses.
+    fn bisect_to_segment(y: f64, target_hue: f64) -> [[f64; 3]; 2] {
+        let mut left = [-1.0; 3];
+        let mut right = left;
+        let (mut left_hue, mut right_hue) = (0.0, 0.0);
+        let mut initialized = false;
+        let mut uncut = true;
+        for n in 0..12 {
+            let Some(mid) = nth_vertex(y, n) else {
+                continue;
+            };
+            let mid_hue = hue_of(mid);
+            if !initialized {
+                left = mid;
+                right = mid;
+                left_hue = mid_hue;
+                right_hue = mid_hue;
+                initialized = true;
+                continue;
+            }
+            if uncut || are_in_cyclic_order(left_hue, mid_hue, right_hue) {
+                uncut = false;
+                if are_in_cyclic_order(left_hue, target_hue, mid_hue) {
+                    right = mid;
+                    right_hue = mid_hue;
+                } else {
+                    left = mid;
+                    left_hue = mid_hue;
+                }
+            }
+        }
+        [left, right]
+    }
+
+    fn midpoint(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+        [0, 1, 2].map(|i| (a[i] + b[i]) / 2.0)
+    }
+
+    fn critical_plane_below(x: f64) -> i32 {
+        (x - 0.5).floor() as i32
+    }
+
+    fn critical_plane_above(x: f64) -> i32 {
+        (x - 0.5).ceil() as i32
+    }
+
+    /// The linear value of the boundary between 8-bit sRGB code values `i`
+    /// and `i + 1`.
+    fn critical_plane(i: i32) -> f64 {
+        linearized((i as f64 + 0.5) / 255.0)
+    }
+
+    /// Find the color on the gamut boundary with luminance `y` and the
+    /// hue, by refining the segment to 8-bit precision.
+    fn bisect_to_limit(y: f64, target_hue: f64) -> [f64; 3] {
+        let [mut left, mut right] = bisect_to_segment(y, target_hue);
+        let mut left_hue = hue_of(left);
+        for axis in 0..3 {
+            if left[axis] == right[axis] {
+                continue;
+            }
+            let (mut l_plane, mut r_plane) = if left[axis] < right[axis] {
+                (
+                    critical_plane_below(true_delinearized(left[axis])),
+                    critical_plane_above(true_delinearized(right[axis])),
+                )
+            } else {
+                (
+                    critical_plane_above(true_delinearized(left[axis])),
+                    critical_plane_below(true_delinearized(right[axis])),
+                )
+            };
+            for _ in 0..8 {
+                if (r_plane - l_plane).abs() <= 1 {
+                    break;
+                }
+                let m_plane = (l_plane + r_plane).div_euclid(2);
+                let mid = set_coordinate(left, critical_plane(m_plane), right, axis);
+                let mid_hue = hue_of(mid);
+                if are_in_cyclic_order(left_hue, target_hue, mid_hue) {
+                    right = mid;
+                    r_plane = m_plane;
+                } else {
+                    left = mid;
+                    left_hue = mid_hue;
+                    l_plane = m_plane;
+                }
+            }
+        }
+        midpoint(left, right)
+    }
+
+    /// Find the in-gamut color with the hue, chroma and luminance `y` with
+    /// Newton's method on CAM16 lightness.
+    fn find_result_by_j(hue: f64, chroma: f64, y: f64) -> Option<[f64; 3]> {
+        let vc = viewing_conditions();
+        let mut j = y.sqrt() * 11.0;
+        for round in 0..5 {
+            let discounted = Cam16::jch_to_discounted_rgb(j, chroma, hue, vc);
+            let linear = multiply_vector(&matrices().linear_from_discount, discounted);
+
+            if linear.iter().any(|c| *c < 0.0) {
+                return None;
+            }
+            let [kr, kg, kb] = SRGB_TO_XYZ[1];
+            let fnj = kr * linear[0] + kg * linear[1] + kb * linear[2];
+            if fnj <= 0.0 {
+                return None;
+            }
+            if round == 4 || (fnj - y).abs() < 0.002 {
+                if linear.iter().any(|c| *c > 100.01) {
+                    return None;
+                }
+                return Some(linear);
+            }
+            // Newton's method on the square root of the luminance, which is
+            // close to linear in J.
+            j -= (fnj - y) * j / (2.0 * fnj);
+        }
+        None
+    }
+
+    /// The linear sRGB in [0 - 100] of the HCT color with the hue in degrees,
+    /// if it is inside the sRGB gamut.
+    pub(super) fn find_in_gamut(hue: f64, chroma: f64, tone: f64) -> Option<[f64; 3]> {
+        let hue = hue.rem_euclid(360.0).to_radians();
+        find_result_by_j(hue, chroma, y_from_lstar(tone))
+    }
+
+    /// The linear sRGB in [0 - 100] of the HCT color with the hue in degrees,
+    /// with the chroma reduced to fit the sRGB gamut.
+    pub(super) fn solve(hue: f64, chroma: f64, tone: f64) -> [f64; 3] {
+        if chroma < 0.0001 || !(0.0001..=99.9999).contains(&tone) {
+            let y = y_from_lstar(tone);
+            return [y, y, y];
+        }
+        let hue = hue.rem_euclid(360.0).to_radians();
+        let y = y_from_lstar(tone);
+        find_result_by_j(hue, chroma, y).unwrap_or_else(|| bisect_to_limit(y, hue))
+    }
+}
+
+/// All the tones of a single hue and chroma.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TonalPalette {
+    hue: f32,
+    chroma: f32,
+}
+
+impl TonalPalette {
+    /// The tones used by Material 3 color roles.
+    pub const TONES: [f32; 13] = [
+        0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 95.0, 99.0, 100.0,
+    ];
+
+    /// Create a palette with the given HCT hue and chroma.
+    pub fn new(hue: f32, chroma: f32) -> Self {
+        Self { hue, chroma }
+    }
+
+    /// Create a palette with the hue and chroma of a color.
+    pub fn from_color(color: &Color) -> Self {
+        let linear = color.clone().into_color_space(ColorSpace::SrgbLinear);
+        let ColorComponents(r, g, b) = *linear.components();
+        let xyz = solver::linear_to_xyz([r, g, b].map(|c| c as f64 * 100.0));
+        let cam = Cam16::from_xyz_f64(xyz, viewing_conditions());
+        Self::new(cam.hue(), cam.chroma())
+    }
+
+    /// The HCT hue of the palette.
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+
+    /// The requested HCT chroma of the palette.
+    pub fn chroma(&self) -> f32 {
+        self.chroma
+    }
+
+    /// The sRGB color in the palette with the given tone in [0 - 100].
+    pub fn tone(&self, tone: f32) -> Color {
+        Color::from_hct(self.hue, self.chroma, tone)
+    }
+
+    /// The colors for each of [TonalPalette::TONES].
+    pub fn tones(&self) -> Vec<Color> {
+        Self::TONES.iter().map(|tone| self.tone(*tone)).collect()
+    }
+}
+
+/// The key palettes derived from a seed color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CorePalette {
+    pub primary: TonalPalette,
+    pub secondary: TonalPalette,
+    pub tertiary: TonalPalette,
+    pub neutral: TonalPalette,
+    pub neutral_variant: TonalPalette,
+    pub error: TonalPalette,
+}
+
+impl CorePalette {
+    /// Derive the key palettes from the hue and chroma of `seed`.
+    pub fn new(seed: &Color) -> Self {
+        let seed = TonalPalette::from_color(seed);
+        let hue = seed.hue();
+
+        Self {
+            primary: TonalPalette::new(hue, seed.chroma().max(48.0)),
+            secondary: TonalPalette::new(hue, 16.0),
+            tertiary: TonalPalette::new((hue + 60.0).rem_euclid(360.0), 24.0),
+            neutral: TonalPalette::new(hue, 4.0),
+            neutral_variant: TonalPalette::new(hue, 8.0),
+            error: TonalPalette::new(25.0, 84.0),
+        }
+    }
+}
+
+/// The colors for each role of a Material 3 color scheme, in sRGB.
+#[derive(Clone, Debug)]
+pub struct Scheme {
+    pub primary: Color,
+    pub on_primary: Color,
+    pub primary_container: Color,
+    pub on_primary_container: Color,
+    pub secondary: Color,
+    pub on_secondary: Color,
+    pub secondary_container: Color,
+    pub on_secondary_container: Color,
+    pub tertiary: Color,
+    pub on_tertiary: Color,
+    pub tertiary_container: Color,
+    pub on_tertiary_container: Color,
+    pub error: Color,
+    pub on_error: Color,
+    pub error_container: Color,
+    pub on_error_container: Color,
+    pub background: Color,
+    pub on_background: Color,
+    pub surface: Color,
+    pub on_surface: Color,
+    pub surface_variant: Color,
+    pub on_surface_variant: Color,
+    pub outline: Color,
+    pub outline_variant: Color,
+    pub shadow: Color,
+    pub scrim: Color,
+    pub inverse_surface: Color,
+    pub inverse_on_surface: Color,
+    pub inverse_primary: Color,
+}
+
+impl Scheme {
+    /// The light scheme for a seed color.
+    pub fn light(seed: &Color) -> Self {
+        let p = CorePalette::new(seed);
+
+        Self {
+            primary: p.primary.tone(40.0),
+            on_primary: p.primary.tone(100.0),
+            primary_container: p.primary.tone(90.0),
+            on_primary_container: p.primary.tone(10.0),
+            secondary: p.secondary.tone(40.0),
+            on_secondary: p.secondary.tone(100.0),
+            secondary_container: p.secondary.tone(90.0),
+            on_secondary_container: p.secondary.tone(10.0),
+            tertiary: p.tertiary.tone(40.0),
+            on_tertiary: p.tertiary.tone(100.0),
+            tertiary_container: p.tertiary.tone(90.0),
+            on_tertiary_container: p.tertiary.tone(10.0),
+            error: p.error.tone(40.0),
+            on_error: p.error.tone(100.0),
+            error_container: p.error.tone(90.0),
+            on_error_container: p.error.tone(10.0),
+            background: p.neutral.tone(99.0),
+            on_background: p.neutral.tone(10.0),
+            surface: p.neutral.tone(99.0),
+            on_surface: p.neutral.tone(10.0),
+            surface_variant: p.neutral_variant.tone(90.0),
+            on_surface_variant: p.neutral_variant.tone(30.0),
+            outline: p.neutral_variant.tone(50.0),
+            outline_variant: p.neutral_variant.tone(80.0),
+            shadow: p.neutral.tone(0.0),
+            scrim: p.neutral.tone(0.0),
+            inverse_surface: p.neutral.tone(20.0),
+            inverse_on_surface: p.neutral.tone(95.0),
+            inverse_primary: p.primary.tone(80.0),
+        }
+    }
+
+    /// The dark scheme for a seed color.
+    pub fn dark(seed: &Color) -> Self {
+        let p = CorePalette::new(seed);
+
+        Self {
+            primary: p.primary.tone(80.0),
+            on_primary: p.primary.tone(20.0),
+            primary_container: p.primary.tone(30.0),
+            on_primary_container: p.primary.tone(90.0),
+            secondary: p.secondary.tone(80.0),
+            on_secondary: p.secondary.tone(20.0),
+            secondary_container: p.secondary.tone(30.0),
+            on_secondary_container: p.secondary.tone(90.0),
+            tertiary: p.tertiary.tone(80.0),
+            on_tertiary: p.tertiary.tone(20.0),
+            tertiary_container: p.tertiary.tone(30.0),
+            on_tertiary_container: p.tertiary.tone(90.0),
+            error: p.error.tone(80.0),
+            on_error: p.error.tone(20.0),
+            error_container: p.error.tone(30.0),
+            on_error_container: p.error.tone(90.0),
+            background: p.neutral.tone(10.0),
+            on_background: p.neutral.tone(90.0),
+            surface: p.neutral.tone(10.0),
+            on_surface: p.neutral.tone(90.0),
+            surface_variant: p.neutral_variant.tone(30.0),
+            on_surface_variant: p.neutral_variant.tone(80.0),
+            outline: p.neutral_variant.tone(60.0),
+            outline_variant: p.neutral_variant.tone(30.0),
+            shadow: p.neutral.tone(0.0),
+            scrim: p.neutral.tone(0.0),
+            inverse_surface: p.neutral.tone(90.0),
+            inverse_on_surface: p.neutral.tone(20.0),
+            inverse_primary: p.primary.tone(40.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn srgb(r: f32, g: f32, b: f32) -> Color {
+        Color::new(ColorSpace::Srgb, ColorComponents(r, g, b))
+    }
+
+    #[test]
+    fn srgb_blue() {
+        let hct = srgb(0.0, 0.0, 1.0).into_color_space(ColorSpace::Hct);
+        let ColorComponents(hue, chroma, tone) = hct.components().clone();
+
+        // Reference values from Material Color Utilities.
+        assert!((hue - 282.788).abs() < 0.1, "{:?}", hct);
+        assert!((chroma - 87.230).abs() < 0.2, "{:?}", hct);
+        assert!((tone - 32.302).abs() < 0.1, "{:?}", hct);
+    }
+
+    #[test]
+    fn solver_keeps_hue_and_tone() {
+        for (hue, tone) in [(30.0, 50.0), (120.0, 90.0), (282.0, 20.0)] {
+            let color = Color::from_hct(hue, 200.0, tone);
+            assert!(color.is_in_gamut(ColorSpace::Srgb));
+
+            let hct = color.into_color_space(ColorSpace::Hct);
+            let ColorComponents(h, c, t) = hct.components().clone();
+            assert!((h - hue).abs() < 1.0, "{:?}", hct);
+            assert!((t - tone).abs() < 0.5, "{:?}", hct);
+            assert!(c < 200.0);
+        }
+    }
+
+    #[test]
+    fn solver_matches_color_space_conversion() {
+        for (hue, chroma, tone) in [(30.0, 40.0, 50.0), (150.0, 20.0, 80.0), (282.0, 60.0, 30.0)] {
+            let solved = Color::from_hct(hue, chroma, tone);
+            let converted = Color::new(ColorSpace::Hct, ColorComponents(hue, chroma, tone))
+                .into_color_space(ColorSpace::Srgb);
+            let (a, b) = (solved.components(), converted.components());
+            assert!((a.0 - b.0).abs() <= 1.0 / 255.0, "{:?} {:?}", a, b);
+            assert!((a.1 - b.1).abs() <= 1.0 / 255.0, "{:?} {:?}", a, b);
+            assert!((a.2 - b.2).abs() <= 1.0 / 255.0, "{:?} {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn tonal_palette_end_points() {
+        let palette = TonalPalette::new(200.0, 30.0);
+        let tones = palette.tones();
+
+        assert_eq!(tones[0].components(), &ColorComponents(0.0, 0.0, 0.0));
+        let white = tones.last().unwrap().components();
+        assert!(white.0 > 0.999 && white.1 > 0.999 && white.2 > 0.999);
+    }
+
+    #[test]
+    fn scheme_roles() {
+        let seed = srgb(0.4, 0.3137, 0.6431);
+        let light = Scheme::light(&seed);
+        let dark = Scheme::dark(&seed);
+
+        let tone = |color: &Color| {
+            color
+                .clone()
+                .into_color_space(ColorSpace::Hct)
+                .components()
+                .2
+        };
+        assert!((tone(&light.primary) - 40.0).abs() < 0.5);
+        assert!((tone(&dark.primary) - 80.0).abs() < 0.5);
+        assert!((tone(&light.error_container) - 90.0).abs() < 0.5);
+    }
+
+    fn hex(color: &Color) -> u32 {
+        let ColorComponents(r, g, b) = *color.components();
+        let byte = |c: f32| (c * 255.0).round() as u32;
+        byte(r) << 16 | byte(g) << 8 | byte(b)
+    }
+
+    #[test]
+    fn material_baseline_scheme() {
+        // Roles for the Material 3 baseline seed #6750A4. Most match the
+        // published baseline tokens. Tertiary, surface, on-surface and the dark
+        // primary and secondary are the values from the scheme tests of
+        // Material Color Utilities instead, since the baseline tokens were
+        // generated by an earlier release that differs by one code value. The
+        // error roles are generated too, unlike the hand-tuned #B3261E.
+        let seed = srgb(103.0 / 255.0, 80.0 / 255.0, 164.0 / 255.0);
+        let light = Scheme::light(&seed);
+        let dark = Scheme::dark(&seed);
+
+        for (color, expected) in [
+            (&light.primary, 0x6750A4),
+            (&light.on_primary, 0xFFFFFF),
+            (&light.secondary, 0x625B71),
+            (&light.on_secondary, 0xFFFFFF),
+            (&light.secondary_container, 0xE8DEF8),
+            (&light.tertiary, 0x7E5260),
+            (&light.error, 0xBA1A1A),
+            (&light.on_error, 0xFFFFFF),
+            (&light.error_container, 0xFFDAD6),
+            (&light.on_error_container, 0x410002),
+            (&light.surface, 0xFFFBFF),
+            (&light.on_surface, 0x1C1B1E),
+            (&dark.primary, 0xCFBCFF),
+            (&dark.on_primary, 0x381E72),
+            (&dark.secondary, 0xCBC2DB),
+            (&dark.on_secondary, 0x332D41),
+            (&dark.secondary_container, 0x4A4458),
+            (&dark.tertiary, 0xEFB8C8),
+            (&dark.tertiary_container, 0x633B48),
+            (&dark.error, 0xFFB4AB),
+            (&dark.on_error, 0x690005),
+            (&dark.error_container, 0x93000A),
+            (&dark.on_error_container, 0xFFDAD6),
+            (&dark.surface, 0x1C1B1E),
+            (&dark.on_surface, 0xE6E1E6),
+        ] {
+            assert_eq!(hex(color), expected, "{:06X}", hex(color));
+        }
+
+        // Near white the chroma must not collapse to a neutral gray.
+        let primary = CorePalette::new(&seed).primary;
+        assert_eq!(hex(&primary.tone(99.0)), 0xFFFBFF);
+    }
+}
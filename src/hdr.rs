@@ -0,0 +1,328 @@
+//! High dynamic range colors as specified by
+//! [CSS Color HDR](https://drafts.csswg.org/css-color-hdr/).
+
+use crate::convert::{hlg_to_linear, linear_to_hlg, Pq, REFERENCE_WHITE};
+use crate::{Color, ColorComponents, ColorSpace};
+
+/// The method used to map colors with more headroom than the target into the
+/// target headroom. Each method scales the components by the same factor so
+/// that hues are preserved.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ToneMapping {
+    /// Apply an extended Reinhard curve to the largest component, which
+    /// leaves dark colors nearly untouched and maps the content peak to the
+    /// target peak.
+    #[default]
+    Reference,
+    /// Apply the electrical-electrical transfer function (EETF) of ITU-R
+    /// BT.2390 to the largest component in the PQ domain.
+    /// https://www.itu.int/pub/R-REP-BT.2390
+    Bt2390,
+    /// Clip the largest component to the target peak.
+    Clip,
+}
+
+/// The limit on the dynamic range of content, from the
+/// [dynamic-range-limit](https://drafts.csswg.org/css-color-hdr/#the-dynamic-range-limit-property)
+/// property.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynamicRangeLimit {
+    /// Limit content to standard dynamic range.
+    Standard,
+    /// Allow some high dynamic range, limited to one stop above media white.
+    ConstrainedHigh,
+    /// Allow the full headroom of the display.
+    NoLimit,
+    /// `dynamic-range-limit-mix()` of limits with percentages in [0 - 100].
+    /// https://drafts.csswg.org/css-color-hdr/#dynamic-range-limit-mix
+    Mix(Vec<(DynamicRangeLimit, f32)>),
+}
+
+impl DynamicRangeLimit {
+    /// The headroom of [DynamicRangeLimit::ConstrainedHigh] in stops.
+    const CONSTRAINED_HIGH_HEADROOM: f32 = 1.0;
+
+    /// The headroom in stops above media white allowed by this limit on a
+    /// display with `display_headroom` stops. A mix is the weighted average of
+    /// the headroom of its limits, with the percentages normalized to sum to
+    /// 100%.
+    pub fn headroom(&self, display_headroom: f32) -> f32 {
+        let display_headroom = display_headroom.max(0.0);
+        match self {
+            DynamicRangeLimit::Standard => 0.0,
+            DynamicRangeLimit::ConstrainedHigh => {
+                Self::CONSTRAINED_HIGH_HEADROOM.min(display_headroom)
+            }
+            DynamicRangeLimit::NoLimit => display_headroom,
+            DynamicRangeLimit::Mix(limits) => {
+                let total: f32 = limits.iter().map(|(_, p)| p.max(0.0)).sum();
+                if total <= 0.0 {
+                    return 0.0;
+                }
+                limits
+                    .iter()
+                    .map(|(limit, p)| limit.headroom(display_headroom) * p.max(0.0) / total)
+                    .sum()
+            }
+        }
+    }
+}
+
+/// The HLG system gamma for a display with the given nominal peak luminance
+/// in cd/m².
+/// https://www.itu.int/rec/R-REC-BT.2100
+pub fn hlg_system_gamma(peak_luminance: f32) -> f32 {
+    1.2 + 0.42 * (peak_luminance / 1000.0).log10()
+}
+
+impl Color {
+    /// The rec2100-hlg components of this color, with the HLG OOTF using the
+    /// given system gamma. Converting to [ColorSpace::Rec2100Hlg] uses a
+    /// system gamma of 1.2.
+    pub fn to_rec2100_hlg(&self, system_gamma: f32) -> ColorComponents {
+        let linear = self.clone().into_color_space(ColorSpace::Rec2100Linear);
+        linear_to_hlg(linear.components(), system_gamma)
+    }
+
+    /// Create a color from rec2100-hlg components, with the HLG OOTF using
+    /// the given system gamma. The result is in [ColorSpace::Rec2100Linear].
+    pub fn from_rec2100_hlg(components: &ColorComponents, system_gamma: f32) -> Color {
+        Color::new(
+            ColorSpace::Rec2100Linear,
+            hlg_to_linear(components, system_gamma),
+        )
+    }
+}
+
+impl Color {
+    /// Map this color from content with `content_headroom` stops above media
+    /// white to a display with `target_headroom` stops. Colors are left
+    /// untouched when the target has at least as much headroom as the
+    /// content. The result is in [ColorSpace::Rec2100Linear].
+    pub fn tone_map(
+        &self,
+        content_headroom: f32,
+        target_headroom: f32,
+        tone_mapping: ToneMapping,
+    ) -> Color {
+        let color = self.clone().into_color_space(ColorSpace::Rec2100Linear);
+        let source_peak = content_headroom.max(0.0).exp2();
+        let target_peak = target_headroom.max(0.0).exp2();
+
+        let ColorComponents(r, g, b) = *color.components();
+        let max = r.max(g).max(b);
+        if max <= 0.0 || target_peak >= source_peak {
+            return color;
+        }
+
+        let mapped = match tone_mapping {
+            ToneMapping::Reference => reinhard(max, source_peak, target_peak),
+            ToneMapping::Bt2390 => bt2390(max, source_peak, target_peak),
+            ToneMapping::Clip => max.min(target_peak),
+        };
+
+        let scale = mapped / max;
+        Color::new(
+            ColorSpace::Rec2100Linear,
+            ColorComponents(r * scale, g * scale, b * scale),
+        )
+        .with_alpha(color.alpha())
+    }
+
+    /// Place this SDR color into [ColorSpace::Rec2100Pq], with sRGB white at
+    /// `reference_white` cd/m². Highlights are expanded by up to
+    /// `highlight_headroom` stops above the reference white with the inverse
+    /// of the [ToneMapping::Reference] curve, so that dark colors keep their
+    /// brightness and sRGB white reaches the peak. A headroom of 0 places the
+    /// color without any expansion.
+    pub fn inverse_tone_map(&self, reference_white: f32, highlight_headroom: f32) -> Color {
+        let color = self.clone().into_color_space(ColorSpace::SrgbLinear);
+        let peak = highlight_headroom.max(0.0).exp2();
+
+        // Expand in the same space that tone mapping uses, so that the two
+        // operations undo each other.
+        let sdr = color.components().copy_and_apply(|v| v.clamp(0.0, 1.0));
+        let linear =
+            Color::new(ColorSpace::SrgbLinear, sdr).into_color_space(ColorSpace::Rec2100Linear);
+        let ColorComponents(r, g, b) = *linear.components();
+        let max = r.max(g).max(b);
+        let scale = if max > 0.0 {
+            inverse_reinhard(max, peak) / max * reference_white / REFERENCE_WHITE
+        } else {
+            0.0
+        };
+
+        Color::new(
+            ColorSpace::Rec2100Linear,
+            ColorComponents(r * scale, g * scale, b * scale),
+        )
+        .with_alpha(color.alpha())
+        .into_color_space(ColorSpace::Rec2100Pq)
+    }
+}
+
+/// The inverse of [reinhard] mapping a value in [0 - 1] back to content with
+/// a peak of `source_peak`.
+fn inverse_reinhard(value: f32, source_peak: f32) -> f32 {
+    // Solve x * (1 + x / peak²) / (1 + x) = value for x.
+    let p2 = source_peak * source_peak;
+    let b = 1.0 - value;
+    (-b + (b * b + 4.0 * value / p2).sqrt()) * p2 / 2.0
+}
+
+/// An extended Reinhard curve that maps `source_peak` to `target_peak` with a
+/// slope of 1 at black.
+fn reinhard(value: f32, source_peak: f32, target_peak: f32) -> f32 {
+    let x = value / target_peak;
+    let white = source_peak / target_peak;
+    target_peak * x * (1.0 + x / (white * white)) / (1.0 + x)
+}
+
+/// The BT.2390 EETF with a black level of 0, for luminance relative to media
+/// white.
+fn bt2390(value: f32, source_peak: f32, target_peak: f32) -> f32 {
+    let pq = |v: f32| Pq::encode(v * REFERENCE_WHITE / Pq::MAX_LUMINANCE);
+
+    let source_max = pq(source_peak);
+    let e1 = pq(value) / source_max;
+    let max_luminance = pq(target_peak) / source_max;
+
+    // The knee where the curve starts to roll off.
+    let knee = 1.5 * max_luminance - 0.5;
+    let e2 = if e1 < knee {
+        e1
+    } else {
+        // Hermite spline from the knee to the target peak.
+        let t = (e1 - knee) / (1.0 - knee);
+        let (t2, t3) = (t * t, t * t * t);
+        (2.0 * t3 - 3.0 * t2 + 1.0) * knee
+            + (t3 - 2.0 * t2 + t) * (1.0 - knee)
+            + (-2.0 * t3 + 3.0 * t2) * max_luminance
+    };
+
+    Pq::decode(e2 * source_max) * Pq::MAX_LUMINANCE / REFERENCE_WHITE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hlg_system_gamma_for_peak_luminance() {
+        assert!((hlg_system_gamma(1000.0) - 1.2).abs() < 1e-6);
+        assert!(hlg_system_gamma(2000.0) > 1.2);
+    }
+
+    #[test]
+    fn hlg_system_gamma_keeps_media_white() {
+        let white = ColorComponents(0.75, 0.75, 0.75);
+        let gray = ColorComponents(0.5, 0.5, 0.5);
+
+        for system_gamma in [1.0, 1.2, 1.5] {
+            let linear = Color::from_rec2100_hlg(&white, system_gamma);
+            assert!((linear.components().1 - 1.0).abs() < 1e-4);
+
+            let back = Color::from_rec2100_hlg(&gray, system_gamma).to_rec2100_hlg(system_gamma);
+            assert!((back.1 - 0.5).abs() < 1e-4, "{:?}", back);
+        }
+
+        // A higher system gamma darkens colors below media white.
+        let low = Color::from_rec2100_hlg(&gray, 1.0).components().1;
+        let high = Color::from_rec2100_hlg(&gray, 1.5).components().1;
+        assert!(high < low);
+    }
+
+    fn linear(r: f32, g: f32, b: f32) -> Color {
+        Color::new(ColorSpace::Rec2100Linear, ColorComponents(r, g, b))
+    }
+
+    #[test]
+    fn tone_mapping_fits_target_headroom() {
+        let peak = linear(8.0, 4.0, 2.0);
+        let dark = linear(0.05, 0.02, 0.01);
+
+        for tone_mapping in [
+            ToneMapping::Reference,
+            ToneMapping::Bt2390,
+            ToneMapping::Clip,
+        ] {
+            let mapped = peak.tone_map(3.0, 0.0, tone_mapping);
+            let ColorComponents(r, g, b) = *mapped.components();
+            assert!((r - 1.0).abs() < 1e-3, "{:?}: {}", tone_mapping, r);
+            // The ratios between components are preserved.
+            assert!((g / r - 0.5).abs() < 1e-4 && (b / r - 0.25).abs() < 1e-4);
+
+            // Dark colors are barely changed.
+            let mapped = dark.tone_map(3.0, 0.0, tone_mapping);
+            assert!(
+                (mapped.components().0 - 0.05).abs() < 5e-3,
+                "{:?}",
+                tone_mapping
+            );
+        }
+
+        // No mapping is needed when the target has enough headroom.
+        let mapped = peak.tone_map(3.0, 3.0, ToneMapping::Reference);
+        assert_eq!(mapped.components(), &ColorComponents(8.0, 4.0, 2.0));
+    }
+
+    #[test]
+    fn dynamic_range_limit_mix() {
+        let display_headroom = 3.0;
+        assert_eq!(DynamicRangeLimit::Standard.headroom(display_headroom), 0.0);
+        assert_eq!(
+            DynamicRangeLimit::ConstrainedHigh.headroom(display_headroom),
+            1.0
+        );
+        assert_eq!(DynamicRangeLimit::NoLimit.headroom(display_headroom), 3.0);
+
+        let mix = DynamicRangeLimit::Mix(vec![
+            (DynamicRangeLimit::Standard, 20.0),
+            (DynamicRangeLimit::NoLimit, 60.0),
+        ]);
+        assert!((mix.headroom(display_headroom) - 2.25).abs() < 1e-6);
+
+        let nested = DynamicRangeLimit::Mix(vec![
+            (mix, 50.0),
+            (DynamicRangeLimit::ConstrainedHigh, 50.0),
+        ]);
+        assert!((nested.headroom(display_headroom) - 1.625).abs() < 1e-6);
+    }
+
+    #[test]
+    fn inverse_tone_mapping_places_sdr_white() {
+        let white = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 1.0, 1.0));
+        let nits = |color: &Color| {
+            color
+                .components()
+                .copy_and_apply(|v| Pq::decode(v) * Pq::MAX_LUMINANCE)
+        };
+
+        let placed = white.inverse_tone_map(100.0, 0.0);
+        assert_eq!(placed.color_space(), ColorSpace::Rec2100Pq);
+        let ColorComponents(r, g, b) = nits(&placed);
+        assert!((r - 100.0).abs() < 0.5 && (g - 100.0).abs() < 0.5 && (b - 100.0).abs() < 0.5);
+
+        // White is expanded to the peak, while dark colors barely change.
+        let expanded = nits(&white.inverse_tone_map(100.0, 2.0));
+        assert!((expanded.0 - 400.0).abs() < 2.0, "{:?}", expanded);
+
+        let dark = Color::new(ColorSpace::SrgbLinear, ColorComponents(0.02, 0.02, 0.02));
+        let ColorComponents(plain, ..) = nits(&dark.inverse_tone_map(100.0, 0.0));
+        let ColorComponents(expanded, ..) = nits(&dark.inverse_tone_map(100.0, 2.0));
+        assert!((expanded - plain).abs() / plain < 0.05);
+
+        // Tone mapping back to SDR undoes the expansion.
+        let color = Color::new(ColorSpace::Srgb, ColorComponents(0.9, 0.5, 0.2));
+        let back = color
+            .inverse_tone_map(REFERENCE_WHITE, 2.0)
+            .tone_map(2.0, 0.0, ToneMapping::Reference)
+            .into_color_space(ColorSpace::Srgb);
+        let ColorComponents(r, g, b) = *back.components();
+        assert!(
+            (r - 0.9).abs() < 1e-3 && (g - 0.5).abs() < 1e-3 && (b - 0.2).abs() < 1e-3,
+            "{:?}",
+            back
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Interpolation between two colors as specified by
+//! [CSS Color 4](https://w3c.github.io/csswg-drafts/css-color-4/#interpolation).
+
+use crate::{Color, ColorComponents, ColorSpace, PremultipliedColor};
+
+/// The method used to interpolate hue angles in polar color spaces.
+/// https://w3c.github.io/csswg-drafts/css-color-4/#hue-interpolation
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum HueInterpolationMethod {
+    /// Take the shorter arc between the two hues.
+    #[default]
+    Shorter,
+    /// Take the longer arc between the two hues.
+    Longer,
+    /// Always interpolate in the direction of increasing hue.
+    Increasing,
+    /// Always interpolate in the direction of decreasing hue.
+    Decreasing,
+}
+
+impl HueInterpolationMethod {
+    /// Adjust the two hues so that linear interpolation between them follows
+    /// the arc selected by this method.
+    pub(crate) fn fixup(&self, hue1: f32, hue2: f32) -> (f32, f32) {
+        let mut hue1 = normalize_hue(hue1);
+        let mut hue2 = normalize_hue(hue2);
+        let delta = hue2 - hue1;
+
+        match self {
+            HueInterpolationMethod::Shorter => {
+                if delta > 180.0 {
+                    hue1 += 360.0;
+                } else if delta < -180.0 {
+                    hue2 += 360.0;
+                }
+            }
+            HueInterpolationMethod::Longer => {
+                if 0.0 < delta && delta < 180.0 {
+                    hue1 += 360.0;
+                } else if -180.0 < delta && delta <= 0.0 {
+                    hue2 += 360.0;
+                }
+            }
+            HueInterpolationMethod::Increasing => {
+                if hue2 < hue1 {
+                    hue2 += 360.0;
+                }
+            }
+            HueInterpolationMethod::Decreasing => {
+                if hue1 < hue2 {
+                    hue1 += 360.0;
+                }
+            }
+        }
+
+        (hue1, hue2)
+    }
+}
+
+/// Normalize a hue angle to the range [0 - 360).
+pub(crate) fn normalize_hue(hue: f32) -> f32 {
+    let hue = hue.rem_euclid(360.0);
+    if hue >= 360.0 {
+        0.0
+    } else {
+        hue
+    }
+}
+
+/// Below this chroma the hue of a polar color is considered powerless.
+pub(crate) const POWERLESS_CHROMA: f32 = 0.000_1;
+
+impl Color {
+    /// Interpolate between this color and `other` in the given color space.
+    /// A `progress` of 0 returns this color and 1 returns `other`.
+    ///
+    /// Components are premultiplied by alpha during interpolation and hues of
+    /// polar color spaces follow the given hue interpolation method. A
+    /// powerless hue takes on the hue of the other color. The result is in
+    /// `color_space`.
+    /// https://w3c.github.io/csswg-drafts/css-color-4/#interpolation
+    pub fn interpolate(
+        &self,
+        other: &Color,
+        progress: f32,
+        color_space: ColorSpace,
+        hue_interpolation: HueInterpolationMethod,
+    ) -> Self {
+        let mut from = self.clone().into_color_space(color_space);
+        let mut to = other.clone().into_color_space(color_space);
+
+        if let Some(hue) = color_space.hue_index() {
+            let (from_hue, to_hue) = carry_forward_hues(&from, &to, hue);
+            let (from_hue, to_hue) = hue_interpolation.fixup(from_hue, to_hue);
+
+            from = with_hue(&from, hue, from_hue);
+            to = with_hue(&to, hue, to_hue);
+        }
+
+        let from = from.premultiply();
+        let to = to.premultiply();
+
+        let lerp = |a: f32, b: f32| a + (b - a) * progress;
+        let a = from.components();
+        let b = to.components();
+        let mut components = ColorComponents(lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2));
+        if let Some(hue) = color_space.hue_index() {
+            components[hue] = normalize_hue(components[hue]);
+        }
+        let alpha = lerp(from.alpha(), to.alpha());
+
+        PremultipliedColor::new(color_space, components, alpha).unpremultiply()
+    }
+}
+
+/// The hues of two polar colors, where a powerless hue is replaced by the hue
+/// of the other color.
+fn carry_forward_hues(from: &Color, to: &Color, hue: usize) -> (f32, f32) {
+    let from = from.components();
+    let to = to.components();
+
+    match (from.1 < POWERLESS_CHROMA, to.1 < POWERLESS_CHROMA) {
+        (true, false) => (to[hue], to[hue]),
+        (false, true) => (from[hue], from[hue]),
+        _ => (from[hue], to[hue]),
+    }
+}
+
+/// A copy of a polar color with the hue at index `hue` replaced.
+fn with_hue(color: &Color, hue: usize, value: f32) -> Color {
+    let mut components = color.components().clone();
+    components[hue] = value;
+    Color::new(color.color_space(), components).with_alpha(color.alpha())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hue_fixup() {
+        let expected = [
+            (HueInterpolationMethod::Shorter, 10.0, 350.0, (370.0, 350.0)),
+            (HueInterpolationMethod::Shorter, 350.0, 10.0, (350.0, 370.0)),
+            (HueInterpolationMethod::Longer, 10.0, 50.0, (370.0, 50.0)),
+            (
+                HueInterpolationMethod::Increasing,
+                50.0,
+                10.0,
+                (50.0, 370.0),
+            ),
+            (
+                HueInterpolationMethod::Decreasing,
+                10.0,
+                50.0,
+                (370.0, 50.0),
+            ),
+        ];
+
+        for (method, hue1, hue2, result) in expected {
+            assert_eq!(method.fixup(hue1, hue2), result, "{:?}", method);
+        }
+    }
+
+    #[test]
+    fn interpolate_premultiplied() {
+        let from = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 0.0, 0.0));
+        let to = Color::new(ColorSpace::Srgb, ColorComponents(0.0, 0.0, 1.0)).with_alpha(0.0);
+
+        let result = from.interpolate(&to, 0.5, ColorSpace::Srgb, HueInterpolationMethod::Shorter);
+
+        // The fully transparent color does not contribute any color.
+        assert_eq!(result.components(), &ColorComponents(1.0, 0.0, 0.0));
+        assert_eq!(result.alpha(), 0.5);
+    }
+
+    #[test]
+    fn interpolate_powerless_hue() {
+        let white = Color::new(ColorSpace::Oklch, ColorComponents(1.0, 0.0, 0.0));
+        let blue = Color::new(ColorSpace::Oklch, ColorComponents(0.5, 0.2, 260.0));
+
+        let result = white.interpolate(
+            &blue,
+            0.5,
+            ColorSpace::Oklch,
+            HueInterpolationMethod::Shorter,
+        );
+
+        assert!((result.components().2 - 260.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn interpolate_hue_first() {
+        let from = Color::new(ColorSpace::Hsluv, ColorComponents(350.0, 80.0, 50.0));
+        let to = Color::new(ColorSpace::Hsluv, ColorComponents(30.0, 0.0, 70.0)).with_alpha(0.5);
+
+        let result = from.interpolate(&to, 0.5, ColorSpace::Hsluv, HueInterpolationMethod::Shorter);
+
+        // The hue is not premultiplied and the powerless hue is carried over.
+        assert!((result.components().0 - 350.0).abs() < 1e-3);
+        assert!((result.components().2 - (50.0 + 35.0) / 1.5).abs() < 1e-3);
+    }
+}
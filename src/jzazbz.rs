@@ -0,0 +1,152 @@
+//! The Jzazbz color space for HDR and wide gamut imagery, which takes the
+//! absolute luminance of colors into account.
+//! https://doi.org/10.1364/OE.25.015131
+
+#![allow(clippy::excessive_precision)]
+
+use crate::convert::{transform, Transform};
+use crate::{Color, ColorComponents, ColorSpace};
+
+const B: f32 = 1.15;
+const G: f32 = 0.66;
+const D: f32 = -0.56;
+const D0: f32 = 1.6295499532821566e-11;
+
+// The perceptual quantizer constants, with a modified exponent `P`.
+const N: f32 = 2610.0 / 16384.0;
+const P: f32 = 1.7 * 2523.0 / 32.0;
+const C1: f32 = 3424.0 / 4096.0;
+const C2: f32 = 2413.0 / 128.0;
+const C3: f32 = 2392.0 / 128.0;
+
+#[rustfmt::skip]
+const XYZ_TO_LMS: Transform = Transform::new(
+    0.41478972, -0.20151,   -0.0166008, 0.0,
+    0.579999,    1.120649,   0.2648,    0.0,
+    0.014648,    0.0531008,  0.6684799, 0.0,
+    0.0,         0.0,        0.0,       1.0,
+);
+
+#[rustfmt::skip]
+const LMS_TO_XYZ: Transform = Transform::new(
+     1.9242264357876067,   0.35031676209499907, -0.09098281098284758, 0.0,
+    -1.0047923125953655,   0.7264811939316552,  -0.312728290523074,   0.0,
+     0.03765140403061801, -0.06538442294808502,  1.5227665613052606,  0.0,
+     0.0,                  0.0,                  0.0,                 1.0,
+);
+
+#[rustfmt::skip]
+const LMS_TO_IAB: Transform = Transform::new(
+    0.5,  3.524,     0.199076, 0.0,
+    0.5, -4.066708,  1.096799, 0.0,
+    0.0,  0.542708, -1.295875, 0.0,
+    0.0,  0.0,       0.0,      1.0,
+);
+
+#[rustfmt::skip]
+const IAB_TO_LMS: Transform = Transform::new(
+    1.0,                   1.0,                   1.0,                  0.0,
+    0.1386050432715393,   -0.1386050432715393,   -0.09601924202631895,  0.0,
+    0.058047316156118876, -0.058047316156118876, -0.811891896056039,    0.0,
+    0.0,                   0.0,                   0.0,                  1.0,
+);
+
+/// Raise the magnitude of `value` to `exponent`, keeping its sign.
+fn signed_pow(value: f32, exponent: f32) -> f32 {
+    value.abs().powf(exponent).copysign(value)
+}
+
+/// Convert relative XYZ with a D65 white point to Jzazbz, where a luminance
+/// of 1 is `reference_white` cd/m².
+pub(crate) fn xyz_to_jzazbz(xyz: &ColorComponents, reference_white: f32) -> ColorComponents {
+    let ColorComponents(x, y, z) = *xyz;
+    let (x, y, z) = (
+        x * reference_white,
+        y * reference_white,
+        z * reference_white,
+    );
+
+    let modified = ColorComponents(B * x - (B - 1.0) * z, G * y - (G - 1.0) * x, z);
+    let lms = transform(&modified, &XYZ_TO_LMS).copy_and_apply(|v| {
+        let v = signed_pow(v / 10000.0, N);
+        signed_pow((C1 + C2 * v) / (1.0 + C3 * v), P)
+    });
+
+    let ColorComponents(iz, az, bz) = transform(&lms, &LMS_TO_IAB);
+    let jz = (1.0 + D) * iz / (1.0 + D * iz) - D0;
+
+    ColorComponents(jz, az, bz)
+}
+
+/// Convert Jzazbz to relative XYZ with a D65 white point, where a luminance
+/// of 1 is `reference_white` cd/m².
+pub(crate) fn jzazbz_to_xyz(jzazbz: &ColorComponents, reference_white: f32) -> ColorComponents {
+    let ColorComponents(jz, az, bz) = *jzazbz;
+    let iz = (jz + D0) / (1.0 + D - D * (jz + D0));
+
+    let lms = transform(&ColorComponents(iz, az, bz), &IAB_TO_LMS).copy_and_apply(|v| {
+        let v = signed_pow(v, 1.0 / P);
+        10000.0 * signed_pow((C1 - v) / (C3 * v - C2), 1.0 / N)
+    });
+
+    let ColorComponents(xm, ym, z) = transform(&lms, &LMS_TO_XYZ);
+    let x = (xm + (B - 1.0) * z) / B;
+    let y = (ym + (G - 1.0) * x) / G;
+
+    ColorComponents(
+        x / reference_white,
+        y / reference_white,
+        z / reference_white,
+    )
+}
+
+impl Color {
+    /// The Jzazbz components of this color, where media white has a
+    /// luminance of `reference_white` cd/m². Converting to
+    /// [ColorSpace::Jzazbz] uses a reference white of 203 cd/m².
+    pub fn to_jzazbz(&self, reference_white: f32) -> ColorComponents {
+        let xyz = self.clone().into_color_space(ColorSpace::XyzD65);
+        xyz_to_jzazbz(xyz.components(), reference_white)
+    }
+
+    /// Create a color from Jzazbz components, where media white has a
+    /// luminance of `reference_white` cd/m². The result is in
+    /// [ColorSpace::XyzD65].
+    pub fn from_jzazbz(jzazbz: &ColorComponents, reference_white: f32) -> Color {
+        Color::new(ColorSpace::XyzD65, jzazbz_to_xyz(jzazbz, reference_white))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::REFERENCE_WHITE;
+
+    #[test]
+    fn media_white() {
+        let white = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 1.0, 1.0));
+        let jzazbz = white.to_jzazbz(REFERENCE_WHITE);
+
+        assert!((jzazbz.0 - 0.2220).abs() < 1e-3, "{:?}", jzazbz);
+        assert!(jzazbz.1.abs() < 1e-3 && jzazbz.2.abs() < 1e-3);
+
+        // Brighter media white has a higher lightness.
+        assert!(white.to_jzazbz(1000.0).0 > jzazbz.0);
+    }
+
+    #[test]
+    fn round_trip_with_reference_white() {
+        let color = Color::new(ColorSpace::Srgb, ColorComponents(0.8, 0.2, 0.4));
+        let back =
+            Color::from_jzazbz(&color.to_jzazbz(400.0), 400.0).into_color_space(ColorSpace::Srgb);
+        let back = back.components();
+
+        assert!(
+            (back.0 - 0.8).abs() < 1e-3
+                && (back.1 - 0.2).abs() < 1e-3
+                && (back.2 - 0.4).abs() < 1e-3,
+            "{:?}",
+            back
+        );
+    }
+}
@@ -1,8 +1,44 @@
 //! Color operations related to the [CSS Color specification](https://w3c.github.io/csswg-drafts/css-color)
 
+mod blend;
+mod cam16;
 mod color;
 mod color_space;
+mod colormap;
 mod convert;
+mod cvd;
+mod delta_e;
+mod filter;
+mod filter_primitive;
+mod gradient;
+mod hct;
+mod hdr;
+mod interpolate;
+mod jzazbz;
+mod palette;
+mod premultiplied;
+mod raster;
+mod rgb_color_space;
+mod scale;
+mod spline;
+mod white_point;
 
+pub use blend::{BlendMode, CompositeOperator};
+pub use cam16::{Cam16, Surround, ViewingConditions};
 pub use color::{Color, ColorComponents};
 pub use color_space::ColorSpace;
+pub use colormap::{Colormap, UniformityReport};
+pub use cvd::Deficiency;
+pub use filter::{ColorInterpolationFilters, FilterFunction};
+pub use filter_primitive::{ColorMatrix, FeColorMatrix, FeComponentTransfer, TransferFunction};
+pub use gradient::{Gradient, GradientItem};
+pub use hct::{CorePalette, Scheme, TonalPalette};
+pub use hdr::{hlg_system_gamma, DynamicRangeLimit, ToneMapping};
+pub use interpolate::HueInterpolationMethod;
+pub use palette::{categorical_palette, minimum_delta_e, PaletteOptions};
+pub use premultiplied::PremultipliedColor;
+pub use raster::{Dither, GradientShape, PixelBuffer, PixelFormat};
+pub use rgb_color_space::{CustomColorSpace, RgbColorSpace, RgbTransferFunction};
+pub use scale::Scale;
+pub use spline::{Spline, SplineKind};
+pub use white_point::{ChromaticAdaptation, WhitePoint};
@@ -0,0 +1,264 @@
+//! Generation of categorical palettes that remain distinguishable under
+//! simulated color vision deficiencies.
+
+use crate::convert::convert;
+use crate::cvd::simulate_linear;
+use crate::delta_e::distance;
+use crate::{Color, ColorComponents, ColorSpace, Deficiency};
+
+/// Options used when generating a categorical palette.
+#[derive(Clone, Debug)]
+pub struct PaletteOptions {
+    /// The gamut every generated color must fit in. Usually
+    /// [ColorSpace::Srgb] or [ColorSpace::DisplayP3].
+    pub gamut: ColorSpace,
+    /// The range of Oklch lightness the colors are picked from.
+    pub lightness: (f32, f32),
+    /// The minimum Oklch chroma of the generated colors.
+    pub min_chroma: f32,
+    /// The color vision deficiencies the palette must remain distinguishable
+    /// under, in addition to normal vision.
+    pub deficiencies: Vec<Deficiency>,
+}
+
+impl Default for PaletteOptions {
+    fn default() -> Self {
+        Self {
+            gamut: ColorSpace::Srgb,
+            lightness: (0.4, 0.9),
+            min_chroma: 0.05,
+            deficiencies: vec![Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan],
+        }
+    }
+}
+
+/// A candidate color with its Oklab components as seen under each of the
+/// vision types.
+struct Candidate {
+    oklch: ColorComponents,
+    seen_as: Vec<ColorComponents>,
+}
+
+impl Candidate {
+    fn new(oklch: ColorComponents, deficiencies: &[Deficiency]) -> Self {
+        let linear = convert(ColorSpace::Oklch, &oklch, ColorSpace::SrgbLinear);
+
+        let mut seen_as = vec![convert(ColorSpace::Oklch, &oklch, ColorSpace::Oklab)];
+        seen_as.extend(deficiencies.iter().map(|deficiency| {
+            let simulated = simulate_linear(&linear, *deficiency, 1.0);
+            convert(ColorSpace::SrgbLinear, &simulated, ColorSpace::Oklab)
+        }));
+
+        Self { oklch, seen_as }
+    }
+
+    /// The smallest ΔEOK between the two candidates under any vision type.
+    fn distance(&self, other: &Candidate) -> f32 {
+        self.seen_as
+            .iter()
+            .zip(other.seen_as.iter())
+            .map(|(a, b)| distance(a, b))
+            .fold(f32::INFINITY, f32::min)
+    }
+}
+
+/// Generate `count` categorical colors in Oklch.
+///
+/// The colors are chosen to maximize the minimum pairwise ΔEOK under normal
+/// vision as well as under each of the deficiencies in `options`, while
+/// staying inside the requested gamut. The result is deterministic.
+///
+/// The colors are picked from a grid of candidates, so fewer than `count`
+/// colors are returned when the gamut, lightness range and minimum chroma
+/// leave fewer candidates than requested. Callers that need exactly `count`
+/// colors should check the length of the result.
+pub fn categorical_palette(count: usize, options: &PaletteOptions) -> Vec<Color> {
+    let candidates = candidates(options);
+    if candidates.is_empty() || count == 0 {
+        return vec![];
+    }
+
+    // Seed the palette with the most chromatic candidate, then greedily add
+    // the candidate that is farthest away from everything picked so far.
+    let mut selected = vec![candidates
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.oklch.1.total_cmp(&b.oklch.1))
+        .map(|(index, _)| index)
+        .unwrap()];
+
+    while selected.len() < count.min(candidates.len()) {
+        let next = (0..candidates.len())
+            .filter(|index| !selected.contains(index))
+            .max_by(|a, b| {
+                let a = min_distance_to(&candidates, *a, &selected);
+                let b = min_distance_to(&candidates, *b, &selected);
+                a.total_cmp(&b)
+            })
+            .unwrap();
+        selected.push(next);
+    }
+
+    // Refine the greedy result by swapping out members while that increases
+    // their distance to the rest of the palette.
+    const MAX_PASSES: usize = 16;
+    for _ in 0..MAX_PASSES {
+        let mut improved = false;
+
+        for slot in 0..selected.len() {
+            let others: Vec<usize> = selected
+                .iter()
+                .enumerate()
+                .filter(|(other_slot, _)| *other_slot != slot)
+                .map(|(_, index)| *index)
+                .collect();
+
+            let current = min_distance_to(&candidates, selected[slot], &others);
+
+            let best = (0..candidates.len())
+                .filter(|index| !selected.contains(index))
+                .map(|index| (index, min_distance_to(&candidates, index, &others)))
+                .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+            if let Some((index, distance)) = best {
+                if distance > current + f32::EPSILON {
+                    selected[slot] = index;
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    selected
+        .into_iter()
+        .map(|index| Color::new(ColorSpace::Oklch, candidates[index].oklch.clone()))
+        .collect()
+}
+
+/// The smallest ΔEOK between any pair of the given colors, under normal vision
+/// and each of the given deficiencies. Useful to validate hand-picked
+/// palettes.
+pub fn minimum_delta_e(colors: &[Color], deficiencies: &[Deficiency]) -> f32 {
+    let candidates: Vec<Candidate> = colors
+        .iter()
+        .map(|color| {
+            let oklch = color.clone().into_color_space(ColorSpace::Oklch);
+            Candidate::new(oklch.components().clone(), deficiencies)
+        })
+        .collect();
+
+    let mut result = f32::INFINITY;
+    for (i, a) in candidates.iter().enumerate() {
+        for b in candidates.iter().skip(i + 1) {
+            result = result.min(a.distance(b));
+        }
+    }
+    result
+}
+
+fn min_distance_to(candidates: &[Candidate], index: usize, others: &[usize]) -> f32 {
+    others
+        .iter()
+        .map(|other| candidates[index].distance(&candidates[*other]))
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Sample the Oklch space on a regular grid and keep all the colors that are
+/// inside the requested gamut.
+fn candidates(options: &PaletteOptions) -> Vec<Candidate> {
+    const LIGHTNESS_STEP: f32 = 0.05;
+    const CHROMA_STEP: f32 = 0.03;
+    const MAX_CHROMA: f32 = 0.37;
+    const HUE_STEP: f32 = 10.0;
+
+    let (min_lightness, max_lightness) = options.lightness;
+
+    let mut result = vec![];
+
+    let mut lightness = min_lightness;
+    while lightness <= max_lightness + f32::EPSILON {
+        let mut chroma = options.min_chroma;
+        while chroma <= MAX_CHROMA {
+            let mut hue = 0.0;
+            while hue < 360.0 {
+                let oklch = ColorComponents(lightness, chroma, hue);
+                let color = Color::new(ColorSpace::Oklch, oklch.clone());
+                if color.is_in_gamut(options.gamut) {
+                    result.push(Candidate::new(oklch, &options.deficiencies));
+                }
+                hue += HUE_STEP;
+            }
+            chroma += CHROMA_STEP;
+        }
+        lightness += LIGHTNESS_STEP;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_requested_number_of_colors_in_gamut() {
+        let options = PaletteOptions::default();
+        let palette = categorical_palette(8, &options);
+
+        assert_eq!(palette.len(), 8);
+        for color in &palette {
+            assert_eq!(color.color_space(), ColorSpace::Oklch);
+            assert!(color.is_in_gamut(ColorSpace::Srgb));
+        }
+    }
+
+    #[test]
+    fn colors_are_distinguishable_under_deficiencies() {
+        let options = PaletteOptions::default();
+        let palette = categorical_palette(6, &options);
+
+        assert!(minimum_delta_e(&palette, &options.deficiencies) > 0.1);
+    }
+
+    #[test]
+    fn respects_wide_gamut() {
+        let options = PaletteOptions {
+            gamut: ColorSpace::DisplayP3,
+            ..Default::default()
+        };
+        let palette = categorical_palette(5, &options);
+
+        assert_eq!(palette.len(), 5);
+        assert!(palette
+            .iter()
+            .all(|color| color.is_in_gamut(ColorSpace::DisplayP3)));
+        assert!(palette
+            .iter()
+            .any(|color| !color.is_in_gamut(ColorSpace::Srgb)));
+    }
+
+    #[test]
+    fn returns_fewer_colors_when_candidates_run_out() {
+        let options = PaletteOptions {
+            lightness: (0.6, 0.6),
+            min_chroma: 0.2,
+            ..Default::default()
+        };
+        let palette = categorical_palette(50, &options);
+
+        assert!(
+            !palette.is_empty() && palette.len() < 50,
+            "{}",
+            palette.len()
+        );
+        for (i, a) in palette.iter().enumerate() {
+            for b in palette.iter().skip(i + 1) {
+                assert_ne!(a.components(), b.components());
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Colors with premultiplied alpha, used for interpolation and compositing.
+
+use crate::{Color, ColorComponents, ColorSpace};
+
+/// A color with its components premultiplied by its alpha, as used by
+/// interpolation and compositing.
+/// https://w3c.github.io/csswg-drafts/css-color-4/#interpolation-alpha
+///
+/// The hue component of polar color spaces is never premultiplied.
+/// Premultiplied components can not be converted to another color space;
+/// convert the color back with [PremultipliedColor::unpremultiply] first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PremultipliedColor {
+    components: ColorComponents,
+    alpha: f32,
+    color_space: ColorSpace,
+}
+
+impl PremultipliedColor {
+    /// Create a color from components that are already premultiplied by
+    /// `alpha`.
+    pub fn new(color_space: ColorSpace, components: ColorComponents, alpha: f32) -> Self {
+        Self {
+            components,
+            alpha,
+            color_space,
+        }
+    }
+
+    /// The premultiplied color components.
+    pub fn components(&self) -> &ColorComponents {
+        &self.components
+    }
+
+    /// The alpha the components are premultiplied with.
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// The color space the components are specified in.
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Divide the components by alpha to get a color with straight alpha.
+    ///
+    /// A fully transparent color has no color information left, so like in
+    /// compositors it becomes transparent black (with the hue of polar color
+    /// spaces preserved).
+    pub fn unpremultiply(&self) -> Color {
+        let alpha = self.alpha;
+        let unpremultiply = |value: f32| if alpha == 0.0 { 0.0 } else { value / alpha };
+
+        let components = map_non_hue(self.color_space, &self.components, unpremultiply);
+        Color::new(self.color_space, components).with_alpha(alpha)
+    }
+}
+
+impl Color {
+    /// Multiply the components of this color by its alpha.
+    pub fn premultiply(&self) -> PremultipliedColor {
+        let alpha = self.alpha();
+        let components = map_non_hue(self.color_space(), self.components(), |value| value * alpha);
+
+        PremultipliedColor::new(self.color_space(), components, alpha)
+    }
+}
+
+impl From<Color> for PremultipliedColor {
+    fn from(color: Color) -> Self {
+        color.premultiply()
+    }
+}
+
+impl From<PremultipliedColor> for Color {
+    fn from(color: PremultipliedColor) -> Self {
+        color.unpremultiply()
+    }
+}
+
+/// Apply `f` to each component, except the hue of polar color spaces.
+fn map_non_hue(
+    color_space: ColorSpace,
+    components: &ColorComponents,
+    f: impl Fn(f32) -> f32,
+) -> ColorComponents {
+    let mut result = ColorComponents(f(components.0), f(components.1), f(components.2));
+    if let Some(hue) = color_space.hue_index() {
+        result[hue] = components[hue];
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let color = Color::new(ColorSpace::Srgb, ColorComponents(0.2, 0.4, 0.6)).with_alpha(0.5);
+        let premultiplied = color.premultiply();
+
+        assert_eq!(premultiplied.components(), &ColorComponents(0.1, 0.2, 0.3));
+        assert_eq!(premultiplied.alpha(), 0.5);
+
+        let back = premultiplied.unpremultiply();
+        assert_eq!(back.components(), color.components());
+        assert_eq!(back.alpha(), color.alpha());
+    }
+
+    #[test]
+    fn hue_is_not_premultiplied() {
+        let color = Color::new(ColorSpace::Oklch, ColorComponents(0.6, 0.2, 120.0)).with_alpha(0.5);
+        let premultiplied = color.premultiply();
+
+        assert_eq!(
+            premultiplied.components(),
+            &ColorComponents(0.3, 0.1, 120.0)
+        );
+    }
+
+    #[test]
+    fn transparent_colors_become_transparent_black() {
+        let color = Color::new(ColorSpace::Srgb, ColorComponents(0.2, 0.4, 0.6)).with_alpha(0.0);
+        let premultiplied = color.premultiply();
+        assert_eq!(premultiplied.components(), &ColorComponents(0.0, 0.0, 0.0));
+        assert_eq!(
+            premultiplied,
+            Color::new(ColorSpace::Srgb, ColorComponents(0.9, 0.1, 0.3))
+                .with_alpha(0.0)
+                .premultiply()
+        );
+
+        let back = premultiplied.unpremultiply();
+        assert_eq!(back.components(), &ColorComponents(0.0, 0.0, 0.0));
+        assert_eq!(back.alpha(), 0.0);
+
+        let oklch = Color::new(ColorSpace::Oklch, ColorComponents(0.6, 0.2, 120.0)).with_alpha(0.0);
+        let back = oklch.premultiply().unpremultiply();
+        assert_eq!(back.components(), &ColorComponents(0.0, 0.0, 120.0));
+    }
+}
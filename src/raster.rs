@@ -0,0 +1,379 @@
+//! Rasterization of gradients into pixel buffers.
+
+use crate::{ColorComponents, ColorSpace, Gradient};
+use std::sync::OnceLock;
+
+/// The geometry used to map pixels to offsets along the gradient line.
+/// Coordinates and lengths are in pixels, angles are in degrees where 0 points
+/// up and angles increase clockwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientShape {
+    /// A `linear-gradient()` with the given angle, passing through the center
+    /// of the image.
+    /// https://w3c.github.io/csswg-drafts/css-images-3/#linear-gradients
+    Linear { angle: f32 },
+    /// A `radial-gradient()` with an elliptical ending shape.
+    /// https://w3c.github.io/csswg-drafts/css-images-3/#radial-gradients
+    Radial {
+        center: (f32, f32),
+        radius: (f32, f32),
+    },
+    /// A `conic-gradient()` starting at the given angle.
+    /// https://w3c.github.io/csswg-drafts/css-images-4/#conic-gradients
+    Conic { center: (f32, f32), from_angle: f32 },
+}
+
+/// The layout of each pixel in a rendered buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PixelFormat {
+    /// 8 bits per channel.
+    Rgba8,
+    /// 16 bits per channel.
+    Rgba16,
+    /// 32-bit floats per channel.
+    Rgba32F,
+}
+
+/// The dithering applied when quantizing to integer pixel formats.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Dither {
+    /// Round to the nearest value.
+    #[default]
+    None,
+    /// An 8x8 Bayer matrix.
+    Ordered,
+    /// A 64x64 blue noise mask generated with the void-and-cluster method.
+    BlueNoise,
+}
+
+/// Rendered pixels in row-major order with straight alpha, in sRGB.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PixelBuffer {
+    Rgba8(Vec<u8>),
+    Rgba16(Vec<u16>),
+    Rgba32F(Vec<f32>),
+}
+
+impl GradientShape {
+    /// The offset along the gradient line for the point `(x, y)`.
+    fn offset(&self, x: f32, y: f32, width: f32, height: f32) -> f32 {
+        match *self {
+            GradientShape::Linear { angle } => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let length = (width * sin).abs() + (height * cos).abs();
+                if length == 0.0 {
+                    return 0.0;
+                }
+                let (dx, dy) = (x - width / 2.0, y - height / 2.0);
+                (dx * sin - dy * cos) / length + 0.5
+            }
+            GradientShape::Radial { center, radius } => {
+                let dx = if radius.0 > 0.0 {
+                    (x - center.0) / radius.0
+                } else {
+                    0.0
+                };
+                let dy = if radius.1 > 0.0 {
+                    (y - center.1) / radius.1
+                } else {
+                    0.0
+                };
+                (dx * dx + dy * dy).sqrt()
+            }
+            GradientShape::Conic { center, from_angle } => {
+                let (dx, dy) = (x - center.0, y - center.1);
+                let angle = dx.atan2(-dy).to_degrees();
+                (angle - from_angle).rem_euclid(360.0) / 360.0
+            }
+        }
+    }
+}
+
+impl Gradient {
+    /// Render the gradient into a buffer of `width` by `height` pixels.
+    ///
+    /// Sampled colors are converted to sRGB and clipped to its gamut. The
+    /// dither is only applied to the color channels, and is ignored for
+    /// [PixelFormat::Rgba32F].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the gradient has no color stops and the buffer is not empty.
+    pub fn render(
+        &self,
+        shape: &GradientShape,
+        width: usize,
+        height: usize,
+        format: PixelFormat,
+        dither: Dither,
+    ) -> PixelBuffer {
+        let mut pixels = Vec::with_capacity(width * height * 4);
+        for y in 0..height {
+            for x in 0..width {
+                let offset =
+                    shape.offset(x as f32 + 0.5, y as f32 + 0.5, width as f32, height as f32);
+                let color = self.sample(offset).into_color_space(ColorSpace::Srgb);
+                let ColorComponents(r, g, b) = color.components().clone();
+                pixels.extend([r, g, b, color.alpha()].map(|value| value.clamp(0.0, 1.0)));
+            }
+        }
+
+        // Alpha is rounded to the nearest value so that edges stay crisp.
+        let threshold = |index: usize| {
+            if index % 4 == 3 {
+                return Dither::None.threshold(0, 0);
+            }
+            let (x, y) = ((index / 4) % width.max(1), (index / 4) / width.max(1));
+            dither.threshold(x, y)
+        };
+
+        match format {
+            PixelFormat::Rgba8 => PixelBuffer::Rgba8(
+                pixels
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| quantize(*value, u8::MAX as f32, threshold(index)) as u8)
+                    .collect(),
+            ),
+            PixelFormat::Rgba16 => PixelBuffer::Rgba16(
+                pixels
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        quantize(*value, u16::MAX as f32, threshold(index)) as u16
+                    })
+                    .collect(),
+            ),
+            PixelFormat::Rgba32F => PixelBuffer::Rgba32F(pixels),
+        }
+    }
+}
+
+/// Quantize a value in the range [0 - 1] to [0 - max] by adding the dither
+/// threshold before truncating.
+fn quantize(value: f32, max: f32, threshold: f32) -> f32 {
+    (value * max + threshold).floor().clamp(0.0, max)
+}
+
+impl Dither {
+    /// The threshold in the range [0 - 1) for the pixel at `(x, y)`.
+    fn threshold(&self, x: usize, y: usize) -> f32 {
+        match self {
+            Dither::None => 0.5,
+            Dither::Ordered => {
+                const SIZE: usize = 8;
+                (bayer(x % SIZE, y % SIZE, SIZE) as f32 + 0.5) / (SIZE * SIZE) as f32
+            }
+            Dither::BlueNoise => {
+                let mask = blue_noise();
+                let rank = mask[(y % BLUE_NOISE_SIZE) * BLUE_NOISE_SIZE + x % BLUE_NOISE_SIZE];
+                (rank as f32 + 0.5) / mask.len() as f32
+            }
+        }
+    }
+}
+
+/// The rank of `(x, y)` in a Bayer matrix of the given power of two size.
+fn bayer(x: usize, y: usize, size: usize) -> usize {
+    if size == 1 {
+        return 0;
+    }
+
+    let half = size / 2;
+    let quadrant = match (x >= half, y >= half) {
+        (false, false) => 0,
+        (true, true) => 1,
+        (true, false) => 2,
+        (false, true) => 3,
+    };
+    4 * bayer(x % half, y % half, half) + quadrant
+}
+
+const BLUE_NOISE_SIZE: usize = 64;
+
+/// A blue noise mask where each entry is the rank of the pixel, generated
+/// once with the void-and-cluster method by Ulichney.
+fn blue_noise() -> &'static [u16] {
+    static MASK: OnceLock<Vec<u16>> = OnceLock::new();
+    MASK.get_or_init(|| void_and_cluster(BLUE_NOISE_SIZE, 1.5))
+}
+
+fn void_and_cluster(size: usize, sigma: f32) -> Vec<u16> {
+    let count = size * size;
+
+    // Gaussian energy contributed by a pixel at a toroidal offset.
+    let mut kernel = vec![0.0; count];
+    for dy in 0..size {
+        for dx in 0..size {
+            let wx = dx.min(size - dx) as f32;
+            let wy = dy.min(size - dy) as f32;
+            kernel[dy * size + dx] = (-(wx * wx + wy * wy) / (2.0 * sigma * sigma)).exp();
+        }
+    }
+
+    let update = |energy: &mut [f32], index: usize, sign: f32| {
+        let (px, py) = (index % size, index / size);
+        for y in 0..size {
+            for x in 0..size {
+                let dx = (x + size - px) % size;
+                let dy = (y + size - py) % size;
+                energy[y * size + x] += sign * kernel[dy * size + dx];
+            }
+        }
+    };
+
+    let tightest_cluster = |pattern: &[bool], energy: &[f32]| {
+        (0..count)
+            .filter(|i| pattern[*i])
+            .max_by(|a, b| energy[*a].total_cmp(&energy[*b]))
+            .unwrap()
+    };
+    let largest_void = |pattern: &[bool], energy: &[f32]| {
+        (0..count)
+            .filter(|i| !pattern[*i])
+            .min_by(|a, b| energy[*a].total_cmp(&energy[*b]))
+            .unwrap()
+    };
+
+    // Start with a deterministic pseudo-random pattern of about 10% ones.
+    let mut state = 0x2545_f491_u32;
+    let mut pattern = vec![false; count];
+    let mut energy = vec![0.0; count];
+    let initial = count / 10;
+    let mut placed = 0;
+    while placed < initial {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        let index = state as usize % count;
+        if !pattern[index] {
+            pattern[index] = true;
+            update(&mut energy, index, 1.0);
+            placed += 1;
+        }
+    }
+
+    // Move ones from the tightest clusters to the largest voids until the
+    // pattern is evenly distributed.
+    loop {
+        let cluster = tightest_cluster(&pattern, &energy);
+        pattern[cluster] = false;
+        update(&mut energy, cluster, -1.0);
+
+        let void = largest_void(&pattern, &energy);
+        pattern[void] = true;
+        update(&mut energy, void, 1.0);
+
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0; count];
+
+    // Phase 1: rank the initial ones by removing the tightest clusters.
+    let mut phase_pattern = pattern.clone();
+    let mut phase_energy = energy.clone();
+    for rank in (0..initial).rev() {
+        let cluster = tightest_cluster(&phase_pattern, &phase_energy);
+        phase_pattern[cluster] = false;
+        update(&mut phase_energy, cluster, -1.0);
+        ranks[cluster] = rank as u16;
+    }
+
+    // Phase 2: rank the remaining pixels by filling the largest voids.
+    for rank in initial..count {
+        let void = largest_void(&pattern, &energy);
+        pattern[void] = true;
+        update(&mut energy, void, 1.0);
+        ranks[void] = rank as u16;
+    }
+
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    fn gray(value: f32) -> Color {
+        Color::new(ColorSpace::Srgb, ColorComponents(value, value, value))
+    }
+
+    #[test]
+    fn linear_gradient_to_right() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(gray(0.0), None)
+            .with_stop(gray(1.0), None);
+
+        let shape = GradientShape::Linear { angle: 90.0 };
+        let buffer = gradient.render(&shape, 4, 1, PixelFormat::Rgba8, Dither::None);
+
+        let PixelBuffer::Rgba8(pixels) = buffer else {
+            panic!("expected an 8-bit buffer");
+        };
+        let reds: Vec<u8> = pixels.iter().step_by(4).copied().collect();
+        assert_eq!(reds, vec![32, 96, 159, 223]);
+        assert!(pixels.iter().skip(3).step_by(4).all(|alpha| *alpha == 255));
+    }
+
+    #[test]
+    fn dither_masks_are_permutations() {
+        let mut bayer_ranks: Vec<usize> = (0..64).map(|i| bayer(i % 8, i / 8, 8)).collect();
+        bayer_ranks.sort();
+        assert_eq!(bayer_ranks, (0..64).collect::<Vec<_>>());
+
+        let mut blue_noise_ranks = blue_noise().to_vec();
+        blue_noise_ranks.sort();
+        assert_eq!(
+            blue_noise_ranks,
+            (0..(BLUE_NOISE_SIZE * BLUE_NOISE_SIZE) as u16).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn dithering_preserves_average() {
+        let gradient = Gradient::new(ColorSpace::Srgb).with_stop(gray(0.3 + 0.5 / 255.0), None);
+        let shape = GradientShape::Conic {
+            center: (8.0, 8.0),
+            from_angle: 0.0,
+        };
+
+        for dither in [Dither::Ordered, Dither::BlueNoise] {
+            let PixelBuffer::Rgba8(pixels) =
+                gradient.render(&shape, 64, 64, PixelFormat::Rgba8, dither)
+            else {
+                panic!("expected an 8-bit buffer");
+            };
+            let reds: Vec<f32> = pixels.iter().step_by(4).map(|v| *v as f32).collect();
+            let average = reds.iter().sum::<f32>() / reds.len() as f32 / 255.0;
+            assert!(
+                (average - (0.3 + 0.5 / 255.0)).abs() < 0.5 / 255.0,
+                "{:?}",
+                dither
+            );
+        }
+    }
+
+    #[test]
+    fn alpha_is_not_dithered() {
+        let gradient = Gradient::new(ColorSpace::Srgb)
+            .with_stop(gray(0.3).with_alpha(0.3 + 0.5 / 255.0), None);
+        let shape = GradientShape::Linear { angle: 90.0 };
+
+        for dither in [Dither::Ordered, Dither::BlueNoise] {
+            let PixelBuffer::Rgba8(pixels) =
+                gradient.render(&shape, 16, 16, PixelFormat::Rgba8, dither)
+            else {
+                panic!("expected an 8-bit buffer");
+            };
+            let first = pixels[3];
+            assert!(pixels
+                .iter()
+                .skip(3)
+                .step_by(4)
+                .all(|alpha| *alpha == first));
+        }
+    }
+}
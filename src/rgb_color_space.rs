@@ -0,0 +1,518 @@
+//! RGB color spaces defined by their primaries, white point and transfer
+//! function, for displays and formats without a [ColorSpace] variant.
+//! http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
+
+use crate::convert::{transform, Hlg, Pq, Transform, REFERENCE_WHITE};
+use crate::white_point::{determinant, invert, multiply, multiply_vector, to_transform, Matrix};
+use crate::{ChromaticAdaptation, Color, ColorComponents, ColorSpace, WhitePoint};
+use std::sync::Mutex;
+
+/// The function that converts the encoded components of an RGB color space
+/// to linear light. Negative values are extended by symmetry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RgbTransferFunction {
+    /// The components are linear light.
+    Linear,
+    /// The piecewise sRGB curve, with a linear segment near black and a 2.4
+    /// power curve.
+    /// https://w3c.github.io/csswg-drafts/css-color-4/#predefined-sRGB
+    Srgb,
+    /// A pure power curve with the given exponent.
+    Gamma(f32),
+    /// The PQ transfer function, where media white is at 203 cd/m².
+    /// https://www.itu.int/rec/R-REC-BT.2100
+    Pq,
+    /// The HLG transfer function, where media white is at an HLG signal of
+    /// 0.75. The OOTF uses the system gamma 1.2 of a 1000 cd/m² display and
+    /// the luminance of the color space.
+    /// https://www.itu.int/rec/R-REC-BT.2100
+    Hlg,
+    /// The ICC parametric curve: `(a * x + b)^g + e` when `x >= d` and
+    /// `c * x + f` otherwise.
+    /// https://www.color.org/specification/ICC.1-2022-05.pdf
+    Parametric {
+        g: f32,
+        a: f32,
+        b: f32,
+        c: f32,
+        d: f32,
+        e: f32,
+        f: f32,
+    },
+}
+
+impl RgbTransferFunction {
+    /// Convert a single encoded component to linear light. HLG is handled
+    /// separately because its OOTF depends on all the components.
+    fn decode(self, value: f32) -> f32 {
+        let abs = value.abs();
+        let linear = match self {
+            RgbTransferFunction::Linear => abs,
+            RgbTransferFunction::Srgb => {
+                if abs < 0.04045 {
+                    abs / 12.92
+                } else {
+                    ((abs + 0.055) / 1.055).powf(2.4)
+                }
+            }
+            RgbTransferFunction::Gamma(gamma) => abs.powf(gamma),
+            RgbTransferFunction::Pq => Pq::decode(abs) * Pq::MAX_LUMINANCE / REFERENCE_WHITE,
+            RgbTransferFunction::Hlg => Hlg::decode(abs),
+            RgbTransferFunction::Parametric {
+                g,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            } => {
+                if abs >= d {
+                    (a * abs + b).max(0.0).powf(g) + e
+                } else {
+                    c * abs + f
+                }
+            }
+        };
+        linear.copysign(value)
+    }
+
+    /// Invert [RgbTransferFunction::decode].
+    fn encode(self, value: f32) -> f32 {
+        let abs = value.abs();
+        let encoded = match self {
+            RgbTransferFunction::Linear => abs,
+            RgbTransferFunction::Srgb => {
+                if abs > 0.0031308 {
+                    1.055 * abs.powf(1.0 / 2.4) - 0.055
+                } else {
+                    12.92 * abs
+                }
+            }
+            RgbTransferFunction::Gamma(gamma) => abs.powf(1.0 / gamma),
+            RgbTransferFunction::Pq => Pq::encode(abs * REFERENCE_WHITE / Pq::MAX_LUMINANCE),
+            RgbTransferFunction::Hlg => Hlg::encode(abs),
+            RgbTransferFunction::Parametric {
+                g,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            } => {
+                if abs >= (a * d + b).max(0.0).powf(g) + e {
+                    ((abs - e).max(0.0).powf(1.0 / g) - b) / a
+                } else if c != 0.0 {
+                    (abs - f) / c
+                } else {
+                    0.0
+                }
+            }
+        };
+        encoded.copysign(value)
+    }
+}
+
+/// The color spaces registered with [RgbColorSpace::register]. Each distinct
+/// definition is stored once.
+static REGISTRY: Mutex<Vec<&'static RgbColorSpace>> = Mutex::new(Vec::new());
+
+/// A handle to an [RgbColorSpace] registered with [RgbColorSpace::register].
+/// Handles to the same definition compare equal.
+#[derive(Clone, Copy, Debug)]
+pub struct CustomColorSpace(&'static RgbColorSpace);
+
+impl CustomColorSpace {
+    /// The registered color space.
+    pub fn rgb_color_space(&self) -> &'static RgbColorSpace {
+        self.0
+    }
+}
+
+impl PartialEq for CustomColorSpace {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for CustomColorSpace {}
+
+/// An RGB color space with matrices derived from the chromaticities of its
+/// primaries and white point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RgbColorSpace {
+    white_point: WhitePoint,
+    transfer_function: RgbTransferFunction,
+    to_xyz: Transform,
+    from_xyz: Transform,
+    /// The contribution of each primary to the luminance.
+    luminance: [f32; 3],
+}
+
+impl RgbColorSpace {
+    /// Create a color space from the xy chromaticities of the red, green and
+    /// blue primaries. The primaries must not lie on a line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the y chromaticity of a primary is not positive, if the
+    /// primaries lie on a line, or if the transfer function can't be inverted
+    /// (a [RgbTransferFunction::Gamma] that isn't positive, or a
+    /// [RgbTransferFunction::Parametric] curve with `a == 0` or `g == 0`).
+    pub fn new(
+        red: (f32, f32),
+        green: (f32, f32),
+        blue: (f32, f32),
+        white_point: WhitePoint,
+        transfer_function: RgbTransferFunction,
+    ) -> Self {
+        for (_, y) in [red, green, blue] {
+            assert!(y > 0.0, "the y chromaticity of a primary must be positive");
+        }
+        match transfer_function {
+            RgbTransferFunction::Gamma(gamma) => {
+                assert!(gamma > 0.0, "the gamma must be positive");
+            }
+            RgbTransferFunction::Parametric { g, a, .. } => {
+                assert!(
+                    a != 0.0 && g != 0.0,
+                    "the parametric curve must have a non-zero `a` and `g`"
+                );
+            }
+            _ => {}
+        }
+
+        let xyz = |(x, y): (f32, f32)| {
+            let (x, y) = (x as f64, y as f64);
+            [x / y, 1.0, (1.0 - x - y) / y]
+        };
+        let (r, g, b) = (xyz(red), xyz(green), xyz(blue));
+        let primaries: Matrix = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+        assert!(
+            determinant(&primaries).abs() > 1e-9,
+            "the primaries must not lie on a line"
+        );
+
+        // Scale each primary so that they add up to the white point.
+        let s = multiply_vector(&invert(&primaries), white_point.xyz_f64());
+        let scale = [[s[0], 0.0, 0.0], [0.0, s[1], 0.0], [0.0, 0.0, s[2]]];
+        let to_xyz = multiply(&primaries, &scale);
+
+        Self {
+            white_point,
+            transfer_function,
+            to_xyz: to_transform(&to_xyz),
+            from_xyz: to_transform(&invert(&to_xyz)),
+            luminance: to_xyz[1].map(|v| v as f32),
+        }
+    }
+
+    /// The white point of the color space.
+    pub fn white_point(&self) -> WhitePoint {
+        self.white_point
+    }
+
+    /// The transfer function of the color space.
+    pub fn transfer_function(&self) -> RgbTransferFunction {
+        self.transfer_function
+    }
+
+    fn luminance(&self, from: &ColorComponents) -> f32 {
+        let [r, g, b] = self.luminance;
+        r * from.0 + g * from.1 + b * from.2
+    }
+
+    fn to_linear_light(&self, from: &ColorComponents) -> ColorComponents {
+        let tf = self.transfer_function;
+        let linear = ColorComponents(tf.decode(from.0), tf.decode(from.1), tf.decode(from.2));
+
+        if tf == RgbTransferFunction::Hlg {
+            Hlg::ootf(&linear, self.luminance(&linear), Hlg::SYSTEM_GAMMA)
+        } else {
+            linear
+        }
+    }
+
+    fn to_gamma_corrected(&self, from: &ColorComponents) -> ColorComponents {
+        let tf = self.transfer_function;
+        let from = if tf == RgbTransferFunction::Hlg {
+            Hlg::inverse_ootf(from, self.luminance(from), Hlg::SYSTEM_GAMMA)
+        } else {
+            from.clone()
+        };
+
+        ColorComponents(tf.encode(from.0), tf.encode(from.1), tf.encode(from.2))
+    }
+
+    /// Register the color space, so that colors can be stored in it and
+    /// converted to and from it as [ColorSpace::Custom] like any predefined
+    /// color space. Registering an identical color space again returns the
+    /// same [ColorSpace]. Registered color spaces are never freed.
+    pub fn register(self) -> ColorSpace {
+        let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let space = match registry.iter().find(|space| ***space == self) {
+            Some(space) => *space,
+            None => {
+                let space: &'static RgbColorSpace = Box::leak(Box::new(self));
+                registry.push(space);
+                space
+            }
+        };
+        ColorSpace::Custom(CustomColorSpace(space))
+    }
+
+    /// Convert components in this color space to XYZ with the white point of
+    /// the color space.
+    pub(crate) fn to_xyz(&self, components: &ColorComponents) -> ColorComponents {
+        transform(&self.to_linear_light(components), &self.to_xyz)
+    }
+
+    /// Convert XYZ with the white point of the color space to components in
+    /// this color space.
+    pub(crate) fn components_from_xyz(&self, xyz: &ColorComponents) -> ColorComponents {
+        self.to_gamma_corrected(&transform(xyz, &self.from_xyz))
+    }
+
+    /// Create a color from components in this color space. The white point
+    /// is adapted to D65 with the Bradford transform and the result is in
+    /// [ColorSpace::XyzD65].
+    pub fn to_color(&self, components: &ColorComponents) -> Color {
+        Color::from_xyz_with_white_point(
+            &self.to_xyz(components),
+            self.white_point,
+            ChromaticAdaptation::Bradford,
+        )
+    }
+
+    /// The components of a color in this color space.
+    pub fn from_color(&self, color: &Color) -> ColorComponents {
+        let xyz = color.to_xyz_with_white_point(self.white_point, ChromaticAdaptation::Bradford);
+        self.components_from_xyz(&xyz)
+    }
+
+    /// Returns true if the color can be displayed in this color space without
+    /// clipping.
+    pub fn is_in_gamut(&self, color: &Color) -> bool {
+        const EPSILON: f32 = 0.000_1;
+
+        let ColorComponents(r, g, b) = self.from_color(color);
+        let range = -EPSILON..=1.0 + EPSILON;
+        range.contains(&r) && range.contains(&g) && range.contains(&b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REC2020: [(f32, f32); 3] = [(0.708, 0.292), (0.170, 0.797), (0.131, 0.046)];
+
+    fn assert_matches(space: &RgbColorSpace, color_space: ColorSpace) {
+        for components in [
+            ColorComponents(0.8, 0.4, 0.1),
+            ColorComponents(0.1, 0.5, 0.9),
+            ColorComponents(0.3, 0.2, 0.25),
+        ] {
+            let expected =
+                Color::new(color_space, components.clone()).into_color_space(ColorSpace::XyzD65);
+            let xyz = space.to_color(&components);
+            let (a, b) = (xyz.components(), expected.components());
+            assert!(
+                (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4 && (a.2 - b.2).abs() < 1e-4,
+                "{:?}: {:?} != {:?}",
+                color_space,
+                a,
+                b
+            );
+
+            let back = space.from_color(&expected);
+            assert!(
+                (back.0 - components.0).abs() < 1e-4
+                    && (back.1 - components.1).abs() < 1e-4
+                    && (back.2 - components.2).abs() < 1e-4,
+                "{:?}: {:?} != {:?}",
+                color_space,
+                back,
+                components
+            );
+        }
+    }
+
+    #[test]
+    fn matches_predefined_color_spaces() {
+        let srgb = RgbColorSpace::new(
+            (0.64, 0.33),
+            (0.30, 0.60),
+            (0.15, 0.06),
+            WhitePoint::D65,
+            RgbTransferFunction::Srgb,
+        );
+        assert_matches(&srgb, ColorSpace::Srgb);
+
+        let [red, green, blue] = REC2020;
+        for (transfer_function, color_space) in [
+            (RgbTransferFunction::Linear, ColorSpace::Rec2100Linear),
+            (RgbTransferFunction::Pq, ColorSpace::Rec2100Pq),
+            (RgbTransferFunction::Hlg, ColorSpace::Rec2100Hlg),
+        ] {
+            let space = RgbColorSpace::new(red, green, blue, WhitePoint::D65, transfer_function);
+            assert_matches(&space, color_space);
+        }
+
+        let acescg = RgbColorSpace::new(
+            (0.713, 0.293),
+            (0.165, 0.830),
+            (0.128, 0.044),
+            WhitePoint::ACES,
+            RgbTransferFunction::Linear,
+        );
+        assert_matches(&acescg, ColorSpace::Acescg);
+    }
+
+    #[test]
+    fn parametric_curve() {
+        // The sRGB curve as an ICC parametric curve, with the Display P3
+        // primaries.
+        let display_p3 = RgbColorSpace::new(
+            (0.680, 0.320),
+            (0.265, 0.690),
+            (0.150, 0.060),
+            WhitePoint::D65,
+            RgbTransferFunction::Parametric {
+                g: 2.4,
+                a: 1.0 / 1.055,
+                b: 0.055 / 1.055,
+                c: 1.0 / 12.92,
+                d: 0.04045,
+                e: 0.0,
+                f: 0.0,
+            },
+        );
+        assert_matches(&display_p3, ColorSpace::DisplayP3);
+
+        let red = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 0.0, 0.0));
+        assert!(display_p3.is_in_gamut(&red));
+        let p3_red = Color::new(ColorSpace::DisplayP3, ColorComponents(1.0, 0.0, 0.0));
+        assert!(!RgbColorSpace::new(
+            (0.64, 0.33),
+            (0.30, 0.60),
+            (0.15, 0.06),
+            WhitePoint::D65,
+            RgbTransferFunction::Gamma(2.2),
+        )
+        .is_in_gamut(&p3_red));
+    }
+
+    #[test]
+    fn registered_color_space() {
+        let [red, green, blue] = REC2020;
+        let space = RgbColorSpace::new(
+            red,
+            green,
+            blue,
+            WhitePoint::D65,
+            RgbTransferFunction::Gamma(2.4),
+        );
+        let expected = space.to_color(&ColorComponents(0.8, 0.4, 0.1));
+        let custom = space.clone().register();
+        assert_eq!(space.register(), custom);
+
+        let color = Color::new(custom, ColorComponents(0.8, 0.4, 0.1));
+        let xyz = color.clone().into_color_space(ColorSpace::XyzD65);
+        let (a, b) = (xyz.components(), expected.components());
+        assert!((a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6 && (a.2 - b.2).abs() < 1e-6);
+
+        let back = xyz.into_color_space(custom);
+        assert!((back.components().0 - 0.8).abs() < 1e-4);
+        assert!(color.is_in_gamut(custom));
+        assert!(!Color::new(custom, ColorComponents(1.1, 0.0, 0.0)).is_in_gamut(custom));
+
+        // Interpolating in the color space mixes the encoded components.
+        let black = Color::new(custom, ColorComponents(0.0, 0.0, 0.0));
+        let white = Color::new(custom, ColorComponents(1.0, 1.0, 1.0));
+        let gray = black.interpolate(&white, 0.5, custom, Default::default());
+        assert_eq!(gray.color_space(), custom);
+        assert!((gray.components().1 - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero `a`")]
+    fn parametric_curve_requires_non_zero_a() {
+        let [red, green, blue] = REC2020;
+        RgbColorSpace::new(
+            red,
+            green,
+            blue,
+            WhitePoint::D65,
+            RgbTransferFunction::Parametric {
+                g: 1.0,
+                a: 0.0,
+                b: 0.5,
+                c: 0.0,
+                d: 0.0,
+                e: 0.0,
+                f: 0.0,
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero `a` and `g`")]
+    fn parametric_curve_requires_non_zero_g() {
+        let [red, green, blue] = REC2020;
+        RgbColorSpace::new(
+            red,
+            green,
+            blue,
+            WhitePoint::D65,
+            RgbTransferFunction::Parametric {
+                g: 0.0,
+                a: 1.0,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+                e: 0.0,
+                f: 0.0,
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "gamma must be positive")]
+    fn gamma_must_be_positive() {
+        let [red, green, blue] = REC2020;
+        RgbColorSpace::new(
+            red,
+            green,
+            blue,
+            WhitePoint::D65,
+            RgbTransferFunction::Gamma(0.0),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "primary must be positive")]
+    fn primary_y_must_be_positive() {
+        let [red, green, _] = REC2020;
+        RgbColorSpace::new(
+            red,
+            green,
+            (0.2, 0.0),
+            WhitePoint::D65,
+            RgbTransferFunction::Linear,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must not lie on a line")]
+    fn primaries_must_not_be_collinear() {
+        RgbColorSpace::new(
+            (0.6, 0.3),
+            (0.4, 0.2),
+            (0.2, 0.1),
+            WhitePoint::D65,
+            RgbTransferFunction::Linear,
+        );
+    }
+}
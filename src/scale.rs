@@ -0,0 +1,318 @@
+//! Color scales that map a numeric domain to colors, modelled after the scales
+//! in [chroma.js](https://gka.github.io/chroma.js/#color-scales).
+
+use crate::{Color, ColorSpace, Gradient, HueInterpolationMethod};
+
+/// How the domain of a scale is divided into classes.
+#[derive(Clone, Debug)]
+enum Classes {
+    /// Equal classes over the current domain.
+    Count(usize),
+    /// Classes between explicit breaks.
+    Breaks(Vec<f32>),
+}
+
+/// A mapping from a numeric domain to a ramp of colors.
+#[derive(Clone, Debug)]
+pub struct Scale {
+    colors: Vec<Color>,
+    color_space: ColorSpace,
+    hue_interpolation: HueInterpolationMethod,
+    domain: Vec<f32>,
+    classes: Option<Classes>,
+    padding: (f32, f32),
+    gamma: f32,
+    correct_lightness: bool,
+    gradient: Gradient,
+}
+
+impl Scale {
+    /// Create a scale that interpolates between the colors in the given color
+    /// space over the domain [0 - 1].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn new(colors: Vec<Color>, color_space: ColorSpace) -> Self {
+        assert!(!colors.is_empty(), "a scale needs at least one color");
+
+        let mut result = Self {
+            colors,
+            color_space,
+            hue_interpolation: HueInterpolationMethod::default(),
+            domain: vec![0.0, 1.0],
+            classes: None,
+            padding: (0.0, 0.0),
+            gamma: 1.0,
+            correct_lightness: false,
+            gradient: Gradient::new(color_space),
+        };
+        result.build_gradient();
+        result
+    }
+
+    /// Set the method used to interpolate hues in polar color spaces.
+    pub fn with_hue_interpolation(mut self, hue_interpolation: HueInterpolationMethod) -> Self {
+        self.hue_interpolation = hue_interpolation;
+        self.build_gradient();
+        self
+    }
+
+    /// Set the domain of the scale. With two values the domain is mapped
+    /// linearly onto the colors. With more values, the domain is mapped
+    /// piecewise, with each segment covering an equal part of the colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than two values are given.
+    pub fn with_domain(mut self, domain: Vec<f32>) -> Self {
+        assert!(domain.len() >= 2, "a domain needs at least two values");
+        self.domain = domain;
+        self
+    }
+
+    /// Divide the domain into `count` equal classes, so that the scale
+    /// returns one distinct color per class. The classes follow the domain,
+    /// even if it is set after this call.
+    pub fn with_classes(mut self, count: usize) -> Self {
+        self.classes = if count > 1 {
+            Some(Classes::Count(count))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Divide the domain into classes at the given breaks, which must be in
+    /// increasing order.
+    pub fn with_class_breaks(mut self, breaks: Vec<f32>) -> Self {
+        self.classes = if breaks.len() > 2 {
+            Some(Classes::Breaks(breaks))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Cut off a fraction of the color ramp at the start and end.
+    pub fn with_padding(mut self, start: f32, end: f32) -> Self {
+        self.padding = (start, end);
+        self
+    }
+
+    /// Apply a gamma to the position along the color ramp. Values above 1
+    /// shift the scale towards the start colors, values below 1 towards the
+    /// end colors.
+    pub fn with_gamma(mut self, gamma: f32) -> Self {
+        self.gamma = gamma;
+        self
+    }
+
+    /// Adjust positions along the ramp so that the Oklab lightness changes
+    /// linearly from the start to the end of the scale.
+    pub fn with_lightness_correction(mut self, correct_lightness: bool) -> Self {
+        self.correct_lightness = correct_lightness;
+        self
+    }
+
+    /// The color for `value` in the domain. The result is in the color space
+    /// of the scale.
+    pub fn sample(&self, value: f32) -> Color {
+        let mut t = self.domain_to_ramp(value);
+
+        if let Some((class, count)) = self.class(value) {
+            t = class as f32 / (count - 1) as f32;
+        }
+
+        if self.correct_lightness {
+            t = self.correct_lightness(t);
+        }
+
+        self.sample_ramp(t)
+    }
+
+    /// `count` colors evenly spaced over the domain.
+    pub fn colors(&self, count: usize) -> Vec<Color> {
+        let (min, max) = self.domain_range();
+        match count {
+            0 => vec![],
+            1 => vec![self.sample((min + max) / 2.0)],
+            _ => (0..count)
+                .map(|i| self.sample(min + (max - min) * i as f32 / (count - 1) as f32))
+                .collect(),
+        }
+    }
+
+    /// The index of the class `value` falls in and the number of classes.
+    fn class(&self, value: f32) -> Option<(usize, usize)> {
+        match self.classes.as_ref()? {
+            Classes::Count(count) => {
+                let (min, max) = self.domain_range();
+                let position = if max != min {
+                    (value - min) / (max - min)
+                } else {
+                    0.0
+                };
+                let class = (position * *count as f32)
+                    .floor()
+                    .clamp(0.0, (count - 1) as f32);
+                Some((class as usize, *count))
+            }
+            Classes::Breaks(breaks) => {
+                let class = breaks
+                    .iter()
+                    .take(breaks.len() - 1)
+                    .rposition(|b| value >= *b)
+                    .unwrap_or(0);
+                Some((class, breaks.len() - 1))
+            }
+        }
+    }
+
+    fn domain_range(&self) -> (f32, f32) {
+        (self.domain[0], *self.domain.last().unwrap())
+    }
+
+    /// Map a value in the domain to a position in [0 - 1] along the ramp.
+    fn domain_to_ramp(&self, value: f32) -> f32 {
+        let segments = self.domain.len() - 1;
+
+        let index = self
+            .domain
+            .iter()
+            .take(segments)
+            .rposition(|d| value >= *d)
+            .unwrap_or(0);
+        let (from, to) = (self.domain[index], self.domain[index + 1]);
+        let progress = if to != from {
+            (value - from) / (to - from)
+        } else {
+            1.0
+        };
+
+        ((index as f32 + progress) / segments as f32).clamp(0.0, 1.0)
+    }
+
+    /// Apply gamma and padding and sample the color ramp.
+    fn sample_ramp(&self, t: f32) -> Color {
+        let mut t = t.clamp(0.0, 1.0);
+        if self.gamma != 1.0 {
+            t = t.powf(self.gamma);
+        }
+        let (start, end) = self.padding;
+        t = (start + t * (1.0 - start - end)).clamp(0.0, 1.0);
+
+        self.gradient.sample(t)
+    }
+
+    /// Find the position along the ramp where the lightness is a linear
+    /// interpolation between the lightness at the start and end.
+    fn correct_lightness(&self, t: f32) -> f32 {
+        const EPSILON: f32 = 0.000_1;
+        const MAX_ITERATIONS: usize = 32;
+
+        let lightness = |t: f32| {
+            self.sample_ramp(t)
+                .into_color_space(ColorSpace::Oklab)
+                .components()
+                .0
+        };
+
+        let (l0, l1) = (lightness(0.0), lightness(1.0));
+        let target = l0 + (l1 - l0) * t;
+        let increasing = l1 > l0;
+
+        let (mut low, mut high) = (0.0, 1.0);
+        let mut result = t;
+        for _ in 0..MAX_ITERATIONS {
+            let l = lightness(result);
+            if (l - target).abs() < EPSILON {
+                break;
+            }
+            if (l > target) == increasing {
+                high = result;
+            } else {
+                low = result;
+            }
+            result = (low + high) / 2.0;
+        }
+        result
+    }
+
+    fn build_gradient(&mut self) {
+        self.gradient = self.colors.iter().fold(
+            Gradient::new(self.color_space).with_hue_interpolation(self.hue_interpolation),
+            |gradient, color| gradient.with_stop(color.clone(), None),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorComponents;
+
+    fn black_to_white() -> Scale {
+        Scale::new(
+            vec![
+                Color::new(ColorSpace::Srgb, ColorComponents(0.0, 0.0, 0.0)),
+                Color::new(ColorSpace::Srgb, ColorComponents(1.0, 1.0, 1.0)),
+            ],
+            ColorSpace::Srgb,
+        )
+    }
+
+    fn red(color: &Color) -> f32 {
+        color.components().0
+    }
+
+    #[test]
+    fn domain() {
+        let scale = black_to_white().with_domain(vec![10.0, 20.0]);
+        assert!((red(&scale.sample(15.0)) - 0.5).abs() < 1e-5);
+
+        let scale = black_to_white().with_domain(vec![0.0, 10.0, 100.0]);
+        assert!((red(&scale.sample(10.0)) - 0.5).abs() < 1e-5);
+        assert!((red(&scale.sample(55.0)) - 0.75).abs() < 1e-5);
+    }
+
+    #[test]
+    fn classes() {
+        let scale = black_to_white().with_classes(3);
+        let reds: Vec<f32> = [0.1, 0.4, 0.9].map(|v| red(&scale.sample(v))).to_vec();
+        assert_eq!(reds, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn classes_follow_domain() {
+        let scale = black_to_white()
+            .with_classes(3)
+            .with_domain(vec![0.0, 30.0]);
+        let reds: Vec<f32> = [5.0, 15.0, 25.0].map(|v| red(&scale.sample(v))).to_vec();
+        assert_eq!(reds, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn padding_and_gamma() {
+        let scale = black_to_white().with_padding(0.25, 0.25);
+        assert!((red(&scale.sample(0.0)) - 0.25).abs() < 1e-5);
+        assert!((red(&scale.sample(1.0)) - 0.75).abs() < 1e-5);
+
+        let scale = black_to_white().with_gamma(2.0);
+        assert!((red(&scale.sample(0.5)) - 0.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn lightness_correction() {
+        let scale = black_to_white().with_lightness_correction(true);
+        let colors = scale.colors(5);
+
+        let lightness: Vec<f32> = colors
+            .into_iter()
+            .map(|color| color.into_color_space(ColorSpace::Oklab).components().0)
+            .collect();
+        for (i, l) in lightness.iter().enumerate() {
+            assert!((l - i as f32 / 4.0).abs() < 1e-3, "{:?}", lightness);
+        }
+    }
+}
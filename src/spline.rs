@@ -0,0 +1,338 @@
+//! Smooth interpolation through multiple colors.
+
+use crate::interpolate::{normalize_hue, POWERLESS_CHROMA};
+use crate::{Color, ColorComponents, ColorSpace, PremultipliedColor};
+
+/// The kind of curve used to interpolate through the colors of a [Spline].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SplineKind {
+    /// A uniform Catmull-Rom spline that passes through every color.
+    #[default]
+    CatmullRom,
+    /// A natural cubic spline that passes through every color with
+    /// continuous second derivatives.
+    NaturalCubic,
+    /// A monotone cubic (Fritsch-Carlson) spline that passes through every
+    /// color and never overshoots between them.
+    MonotoneCubic,
+    /// A uniform cubic B-spline that starts and ends at the first and last
+    /// colors, and is pulled towards the colors in between.
+    BSpline,
+}
+
+/// A smooth curve through a list of colors, evenly spaced over [0 - 1].
+///
+/// Components are premultiplied by alpha and interpolated in the given color
+/// space. Hues in polar color spaces are unwrapped so that each step takes
+/// the shorter arc.
+#[derive(Clone, Debug)]
+pub struct Spline {
+    kind: SplineKind,
+    color_space: ColorSpace,
+    channels: [Channel; 4],
+}
+
+impl Spline {
+    /// Create a spline through the given colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn new(colors: &[Color], color_space: ColorSpace, kind: SplineKind) -> Self {
+        assert!(!colors.is_empty(), "a spline needs at least one color");
+
+        let mut colors: Vec<Color> = colors
+            .iter()
+            .map(|color| color.clone().into_color_space(color_space))
+            .collect();
+
+        if let Some(hue) = color_space.hue_index() {
+            unwrap_hues(&mut colors, color_space, hue);
+        }
+
+        let mut values: [Vec<f32>; 4] = Default::default();
+        for color in &colors {
+            let premultiplied = color.premultiply();
+            let ColorComponents(c0, c1, c2) = premultiplied.components().clone();
+            for (channel, value) in [c0, c1, c2, premultiplied.alpha()].into_iter().enumerate() {
+                values[channel].push(value);
+            }
+        }
+
+        Self {
+            kind,
+            color_space,
+            channels: values.map(|values| Channel::new(values, kind)),
+        }
+    }
+
+    /// The kind of curve used by the spline.
+    pub fn kind(&self) -> SplineKind {
+        self.kind
+    }
+
+    /// Sample the spline at `t`, which is clamped to [0 - 1]. The result is
+    /// in the color space of the spline.
+    pub fn sample(&self, t: f32) -> Color {
+        let [c0, c1, c2, alpha] = [0, 1, 2, 3].map(|i| self.channels[i].evaluate(t));
+        let mut components = ColorComponents(c0, c1, c2);
+        if let Some(hue) = self.color_space.hue_index() {
+            components[hue] = normalize_hue(components[hue]);
+        }
+
+        PremultipliedColor::new(self.color_space, components, alpha.clamp(0.0, 1.0)).unpremultiply()
+    }
+}
+
+/// Make consecutive hues differ by at most 180 degrees, carrying hues forward
+/// over colors where the hue is powerless.
+fn unwrap_hues(colors: &mut [Color], color_space: ColorSpace, hue_index: usize) {
+    let Some(first_hue) = colors
+        .iter()
+        .find(|color| color.components().1 >= POWERLESS_CHROMA)
+        .map(|color| color.components()[hue_index])
+    else {
+        return;
+    };
+
+    let mut previous = first_hue;
+    for color in colors.iter_mut() {
+        let mut components = color.components().clone();
+        let mut hue = if components.1 < POWERLESS_CHROMA {
+            previous
+        } else {
+            components[hue_index]
+        };
+        hue += ((previous - hue) / 360.0).round() * 360.0;
+        previous = hue;
+        components[hue_index] = hue;
+        *color = Color::new(color_space, components).with_alpha(color.alpha());
+    }
+}
+
+/// The values of a single channel and the derivatives needed to evaluate the
+/// curve through them.
+#[derive(Clone, Debug, Default)]
+struct Channel {
+    kind: SplineKind,
+    values: Vec<f32>,
+    /// First derivatives for monotone splines and second derivatives for
+    /// natural splines, with respect to the segment parameter.
+    derivatives: Vec<f32>,
+}
+
+impl Channel {
+    fn new(values: Vec<f32>, kind: SplineKind) -> Self {
+        let derivatives = match kind {
+            SplineKind::NaturalCubic => natural_second_derivatives(&values),
+            SplineKind::MonotoneCubic => monotone_tangents(&values),
+            SplineKind::CatmullRom | SplineKind::BSpline => vec![],
+        };
+
+        Self {
+            kind,
+            values,
+            derivatives,
+        }
+    }
+
+    /// The value of point `i`, where points before the first and after the
+    /// last are reflected.
+    fn point(&self, i: isize) -> f32 {
+        let n = self.values.len() as isize;
+        if i < 0 {
+            2.0 * self.values[0] - self.values[1.min(n - 1) as usize]
+        } else if i >= n {
+            2.0 * self.values[(n - 1) as usize] - self.values[(n - 2).max(0) as usize]
+        } else {
+            self.values[i as usize]
+        }
+    }
+
+    fn evaluate(&self, t: f32) -> f32 {
+        let segments = self.values.len() - 1;
+        if segments == 0 {
+            return self.values[0];
+        }
+
+        let position = t.clamp(0.0, 1.0) * segments as f32;
+        let i = (position.floor() as usize).min(segments - 1);
+        let u = position - i as f32;
+
+        let p0 = self.point(i as isize - 1);
+        let p1 = self.point(i as isize);
+        let p2 = self.point(i as isize + 1);
+        let p3 = self.point(i as isize + 2);
+
+        match self.kind {
+            SplineKind::CatmullRom => {
+                0.5 * (2.0 * p1
+                    + (p2 - p0) * u
+                    + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u * u
+                    + (3.0 * p1 - p0 - 3.0 * p2 + p3) * u * u * u)
+            }
+            SplineKind::BSpline => {
+                let u2 = u * u;
+                let u3 = u2 * u;
+                ((1.0 - u).powi(3) * p0
+                    + (3.0 * u3 - 6.0 * u2 + 4.0) * p1
+                    + (-3.0 * u3 + 3.0 * u2 + 3.0 * u + 1.0) * p2
+                    + u3 * p3)
+                    / 6.0
+            }
+            SplineKind::NaturalCubic => {
+                let (m1, m2) = (self.derivatives[i], self.derivatives[i + 1]);
+                let v = 1.0 - u;
+                v * p1 + u * p2 + ((v * v * v - v) * m1 + (u * u * u - u) * m2) / 6.0
+            }
+            SplineKind::MonotoneCubic => {
+                let (m1, m2) = (self.derivatives[i], self.derivatives[i + 1]);
+                let u2 = u * u;
+                let u3 = u2 * u;
+                (2.0 * u3 - 3.0 * u2 + 1.0) * p1
+                    + (u3 - 2.0 * u2 + u) * m1
+                    + (-2.0 * u3 + 3.0 * u2) * p2
+                    + (u3 - u2) * m2
+            }
+        }
+    }
+}
+
+/// Solve the tridiagonal system for the second derivatives of a natural cubic
+/// spline through uniformly spaced values.
+fn natural_second_derivatives(values: &[f32]) -> Vec<f32> {
+    let n = values.len();
+    let mut result = vec![0.0; n];
+    if n < 3 {
+        return result;
+    }
+
+    // Thomas algorithm for: m[i-1] + 4 m[i] + m[i+1] = 6 (y[i-1] - 2 y[i] + y[i+1])
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+    for i in 1..n - 1 {
+        let rhs = 6.0 * (values[i - 1] - 2.0 * values[i] + values[i + 1]);
+        let denominator = 4.0 - c[i - 1];
+        c[i] = 1.0 / denominator;
+        d[i] = (rhs - d[i - 1]) / denominator;
+    }
+    for i in (1..n - 1).rev() {
+        result[i] = d[i] - c[i] * result[i + 1];
+    }
+    result
+}
+
+/// Tangents for a monotone cubic Hermite spline using the Fritsch-Carlson
+/// method.
+fn monotone_tangents(values: &[f32]) -> Vec<f32> {
+    let n = values.len();
+    if n < 2 {
+        return vec![0.0; n];
+    }
+
+    let secants: Vec<f32> = values.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let mut tangents = vec![0.0; n];
+    tangents[0] = secants[0];
+    tangents[n - 1] = secants[n - 2];
+    for i in 1..n - 1 {
+        tangents[i] = if secants[i - 1] * secants[i] <= 0.0 {
+            0.0
+        } else {
+            (secants[i - 1] + secants[i]) / 2.0
+        };
+    }
+
+    for i in 0..n - 1 {
+        if secants[i] == 0.0 {
+            tangents[i] = 0.0;
+            tangents[i + 1] = 0.0;
+            continue;
+        }
+
+        let alpha = tangents[i] / secants[i];
+        let beta = tangents[i + 1] / secants[i];
+        let length = (alpha * alpha + beta * beta).sqrt();
+        if length > 3.0 {
+            let tau = 3.0 / length;
+            tangents[i] = tau * alpha * secants[i];
+            tangents[i + 1] = tau * beta * secants[i];
+        }
+    }
+
+    tangents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors() -> Vec<Color> {
+        [
+            (0.0, 0.0, 0.0),
+            (1.0, 0.2, 0.0),
+            (0.9, 1.0, 0.1),
+            (1.0, 1.0, 1.0),
+        ]
+        .into_iter()
+        .map(|(r, g, b)| Color::new(ColorSpace::Srgb, ColorComponents(r, g, b)))
+        .collect()
+    }
+
+    #[test]
+    fn interpolating_splines_pass_through_colors() {
+        let colors = colors();
+        for kind in [
+            SplineKind::CatmullRom,
+            SplineKind::NaturalCubic,
+            SplineKind::MonotoneCubic,
+        ] {
+            let spline = Spline::new(&colors, ColorSpace::Srgb, kind);
+            for (index, color) in colors.iter().enumerate() {
+                let sample = spline.sample(index as f32 / 3.0);
+                let (a, b) = (sample.components(), color.components());
+                assert!(
+                    (a.0 - b.0).abs() < 1e-5
+                        && (a.1 - b.1).abs() < 1e-5
+                        && (a.2 - b.2).abs() < 1e-5,
+                    "{:?}: {:?} != {:?}",
+                    kind,
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn b_spline_is_clamped_to_end_points() {
+        let colors = colors();
+        let spline = Spline::new(&colors, ColorSpace::Srgb, SplineKind::BSpline);
+
+        assert!((spline.sample(0.0).components().0 - 0.0).abs() < 1e-5);
+        assert!((spline.sample(1.0).components().0 - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn monotone_spline_does_not_overshoot() {
+        let colors = colors();
+        let spline = Spline::new(&colors, ColorSpace::Srgb, SplineKind::MonotoneCubic);
+
+        for step in 0..=100 {
+            let red = spline.sample(step as f32 / 100.0).components().0;
+            assert!((-1e-5..=1.0 + 1e-5).contains(&red), "{}", red);
+        }
+    }
+
+    #[test]
+    fn polar_hues_are_unwrapped() {
+        let colors: Vec<Color> = [350.0, 10.0, 30.0]
+            .into_iter()
+            .map(|hue| Color::new(ColorSpace::Oklch, ColorComponents(0.7, 0.1, hue)))
+            .collect();
+        let spline = Spline::new(&colors, ColorSpace::Oklch, SplineKind::CatmullRom);
+
+        let hue = spline.sample(0.25).components().2;
+        assert!(!(30.0..=350.0).contains(&hue), "{}", hue);
+    }
+}
@@ -0,0 +1,423 @@
+//! White points and the chromatic adaptation transforms between them.
+//! http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+
+#![allow(clippy::excessive_precision)]
+
+use crate::convert::{self, transform, Transform};
+use crate::{Color, ColorComponents, ColorSpace};
+
+/// A white point, as the CIE 1931 xy chromaticity of a reference white with a
+/// luminance of 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WhitePoint {
+    x: f32,
+    y: f32,
+}
+
+impl WhitePoint {
+    /// CIE standard illuminant A, a tungsten filament lamp.
+    pub const A: Self = Self::new(0.44757, 0.40745);
+    /// CIE illuminant C, average daylight.
+    pub const C: Self = Self::new(0.31006, 0.31616);
+    /// CIE standard illuminant D50, horizon light. Uses the chromaticity from
+    /// CSS Color.
+    pub const D50: Self = Self::new(0.3457, 0.3585);
+    /// CIE illuminant D55, mid-morning daylight.
+    pub const D55: Self = Self::new(0.33242, 0.34743);
+    /// CIE illuminant D60.
+    pub const D60: Self = Self::new(0.32163, 0.33774);
+    /// CIE standard illuminant D65, noon daylight. Uses the chromaticity from
+    /// CSS Color.
+    pub const D65: Self = Self::new(0.3127, 0.3290);
+    /// CIE illuminant D75, north sky daylight.
+    pub const D75: Self = Self::new(0.29902, 0.31485);
+    /// The equal energy illuminant E.
+    pub const E: Self = Self::new(1.0 / 3.0, 1.0 / 3.0);
+    /// CIE illuminant F1, daylight fluorescent.
+    pub const F1: Self = Self::new(0.31310, 0.33727);
+    /// CIE illuminant F2, cool white fluorescent.
+    pub const F2: Self = Self::new(0.37208, 0.37529);
+    /// CIE illuminant F3, white fluorescent.
+    pub const F3: Self = Self::new(0.40910, 0.39430);
+    /// CIE illuminant F4, warm white fluorescent.
+    pub const F4: Self = Self::new(0.44018, 0.40329);
+    /// CIE illuminant F5, daylight fluorescent.
+    pub const F5: Self = Self::new(0.31379, 0.34531);
+    /// CIE illuminant F6, lite white fluorescent.
+    pub const F6: Self = Self::new(0.37790, 0.38835);
+    /// CIE illuminant F7, broadband D65 simulator.
+    pub const F7: Self = Self::new(0.31292, 0.32933);
+    /// CIE illuminant F8, broadband D50 simulator.
+    pub const F8: Self = Self::new(0.34588, 0.35875);
+    /// CIE illuminant F9, broadband cool white deluxe.
+    pub const F9: Self = Self::new(0.37417, 0.37281);
+    /// CIE illuminant F10, narrowband 5000K.
+    pub const F10: Self = Self::new(0.34609, 0.35986);
+    /// CIE illuminant F11, narrowband 4000K.
+    pub const F11: Self = Self::new(0.38052, 0.37713);
+    /// CIE illuminant F12, narrowband 3000K.
+    pub const F12: Self = Self::new(0.43695, 0.40441);
+    /// The ACES white point, which is close to D60.
+    /// https://docs.acescentral.com/specifications/aces2065-1/
+    pub const ACES: Self = Self::new(0.32168, 0.33767);
+
+    /// Create a white point from its xy chromaticity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is not positive, since the tristimulus values are
+    /// divided by it.
+    pub const fn new(x: f32, y: f32) -> Self {
+        assert!(
+            y > 0.0,
+            "the y chromaticity of a white point must be positive"
+        );
+        Self { x, y }
+    }
+
+    /// The x chromaticity coordinate.
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// The y chromaticity coordinate.
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// The XYZ tristimulus values of the white point with a luminance of 1.
+    pub fn xyz(&self) -> ColorComponents {
+        ColorComponents(self.x / self.y, 1.0, (1.0 - self.x - self.y) / self.y)
+    }
+
+    /// The XYZ values in double precision, so that adaptation matrices are
+    /// accurate to the precision of the components.
+    pub(crate) fn xyz_f64(&self) -> [f64; 3] {
+        let (x, y) = (self.x as f64, self.y as f64);
+        [x / y, 1.0, (1.0 - x - y) / y]
+    }
+}
+
+/// A chromatic adaptation transform (CAT) to convert colors between white
+/// points, by scaling the responses of a cone space.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform, which CSS Color uses to convert between D50
+    /// and D65.
+    #[default]
+    Bradford,
+    /// The transform of the CIECAM02 appearance model.
+    Cat02,
+    /// The transform of the CAM16 appearance model.
+    Cat16,
+    /// The von Kries transform, with the Hunt-Pointer-Estevez cone space
+    /// normalized to D65.
+    VonKries,
+    /// Scaling the XYZ values directly.
+    XyzScaling,
+}
+
+pub(crate) type Matrix = [[f64; 3]; 3];
+
+impl ChromaticAdaptation {
+    /// The matrix from XYZ to the cone space of the transform.
+    fn cone_response(&self) -> Matrix {
+        match self {
+            ChromaticAdaptation::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            ChromaticAdaptation::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.0030, 0.0136, 0.9834],
+            ],
+            ChromaticAdaptation::Cat16 => [
+                [0.401288, 0.650173, -0.051461],
+                [-0.250268, 1.204414, 0.045854],
+                [-0.002079, 0.048952, 0.953127],
+            ],
+            ChromaticAdaptation::VonKries => [
+                [0.40024, 0.70760, -0.08081],
+                [-0.22630, 1.16532, 0.04570],
+                [0.0, 0.0, 0.91822],
+            ],
+            ChromaticAdaptation::XyzScaling => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Compute the matrix that adapts XYZ values from one white point to
+    /// another.
+    fn compute_matrix(&self, from: WhitePoint, to: WhitePoint) -> Transform {
+        let cone_response = self.cone_response();
+        let source = multiply_vector(&cone_response, from.xyz_f64());
+        let destination = multiply_vector(&cone_response, to.xyz_f64());
+
+        let mut scale = [[0.0; 3]; 3];
+        for i in 0..3 {
+            scale[i][i] = destination[i] / source[i];
+        }
+
+        to_transform(&multiply(
+            &invert(&cone_response),
+            &multiply(&scale, &cone_response),
+        ))
+    }
+
+    /// The matrix that adapts XYZ values from one white point to another. The
+    /// Bradford matrices between the white points of the predefined color
+    /// spaces are constants, and the others are computed on demand.
+    pub(crate) fn matrix(&self, from: WhitePoint, to: WhitePoint) -> Transform {
+        if *self == ChromaticAdaptation::Bradford {
+            for (source, destination, matrix) in &BRADFORD {
+                if from == *source && to == *destination {
+                    return *matrix;
+                }
+            }
+        }
+        self.compute_matrix(from, to)
+    }
+
+    /// Adapt XYZ components from one white point to another.
+    pub fn adapt(
+        &self,
+        from: WhitePoint,
+        to: WhitePoint,
+        xyz: &ColorComponents,
+    ) -> ColorComponents {
+        if from == to {
+            return xyz.clone();
+        }
+        transform(xyz, &self.matrix(from, to))
+    }
+}
+
+/// The Bradford matrices between D50, D65 and the ACES white point, which the
+/// predefined color spaces convert between.
+#[rustfmt::skip]
+const BRADFORD: [(WhitePoint, WhitePoint, Transform); 4] = [
+    (WhitePoint::D65, WhitePoint::D50, Transform::new(
+         1.0479298208405488,    0.029627815688159344, -0.009243058152591178, 0.0,
+         0.022946793341019088,  0.990434484573249,     0.015055144896577895, 0.0,
+        -0.05019222954313557,  -0.01707382502938514,   0.7518742899580008,   0.0,
+         0.0,                   0.0,                   0.0,                  1.0,
+    )),
+    (WhitePoint::D50, WhitePoint::D65, Transform::new(
+         0.9554734527042182,   -0.028369706963208136,  0.012314001688319899, 0.0,
+        -0.023098536874261423,  1.0099954580058226,   -0.020507696433477912, 0.0,
+         0.0632593086610217,    0.021041398966943008,  1.3303659366080753,   0.0,
+         0.0,                   0.0,                   0.0,                  1.0,
+    )),
+    (WhitePoint::ACES, WhitePoint::D65, Transform::new(
+         0.9872240087030176,   -0.0075983718116623,    0.003072577058531529, 0.0,
+        -0.00611322860685685,   1.0018614847396539,   -0.005095961511130595, 0.0,
+         0.015953288335912624,  0.005330035791388932,  1.0816806030657953,   0.0,
+         0.0,                   0.0,                   0.0,                  1.0,
+    )),
+    (WhitePoint::D65, WhitePoint::ACES, Transform::new(
+         1.013034914649986,     0.007698230125414972, -0.002841317432439071, 0.0,
+         0.006105257823207134,  0.9981633521182776,    0.004685156722537226, 0.0,
+        -0.014970943626587517, -0.005032038535111868,  0.9245061374576631,   0.0,
+         0.0,                   0.0,                   0.0,                  1.0,
+    )),
+];
+
+/// Convert a matrix to a transform, which stores the columns of the matrix as
+/// rows.
+pub(crate) fn to_transform(m: &Matrix) -> Transform {
+    Transform::new(
+        m[0][0] as f32,
+        m[1][0] as f32,
+        m[2][0] as f32,
+        0.0,
+        m[0][1] as f32,
+        m[1][1] as f32,
+        m[2][1] as f32,
+        0.0,
+        m[0][2] as f32,
+        m[1][2] as f32,
+        m[2][2] as f32,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+    )
+}
+
+pub(crate) fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    result
+}
+
+pub(crate) fn multiply_vector(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    [0, 1, 2].map(|i| m[i][0] * v[0] + m[i][1] * v[1] + m[i][2] * v[2])
+}
+
+pub(crate) fn determinant(m: &Matrix) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+pub(crate) fn invert(m: &Matrix) -> Matrix {
+    // The cofactor of each element, which gives the inverse when transposed
+    // and divided by the determinant.
+    let cofactor = |i: usize, j: usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+
+    let determinant = determinant(m);
+
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = cofactor(j, i) / determinant;
+        }
+    }
+    result
+}
+
+impl Color {
+    /// The XYZ components of this color relative to `white_point`, adapted
+    /// from the white point of the color's color space with the given
+    /// chromatic adaptation.
+    pub fn to_xyz_with_white_point(
+        &self,
+        white_point: WhitePoint,
+        adaptation: ChromaticAdaptation,
+    ) -> ColorComponents {
+        let (xyz, from) = convert::color_space_to_xyz(self.color_space(), self.components());
+        adaptation.adapt(from, white_point, &xyz)
+    }
+
+    /// Create a color from XYZ components relative to `white_point`, adapted
+    /// to D65 with the given chromatic adaptation. The result is in
+    /// [ColorSpace::XyzD65].
+    pub fn from_xyz_with_white_point(
+        xyz: &ColorComponents,
+        white_point: WhitePoint,
+        adaptation: ChromaticAdaptation,
+    ) -> Color {
+        Color::new(
+            ColorSpace::XyzD65,
+            adaptation.adapt(white_point, WhitePoint::D65, xyz),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: &ColorComponents, b: &ColorComponents, tolerance: f32) -> bool {
+        (a.0 - b.0).abs() < tolerance
+            && (a.1 - b.1).abs() < tolerance
+            && (a.2 - b.2).abs() < tolerance
+    }
+
+    #[test]
+    fn adaptation_maps_white_to_white() {
+        for adaptation in [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::Cat02,
+            ChromaticAdaptation::Cat16,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::XyzScaling,
+        ] {
+            for white_point in [
+                WhitePoint::A,
+                WhitePoint::D50,
+                WhitePoint::F11,
+                WhitePoint::E,
+            ] {
+                let adapted =
+                    adaptation.adapt(WhitePoint::D65, white_point, &WhitePoint::D65.xyz());
+                assert!(
+                    close(&adapted, &white_point.xyz(), 1e-6),
+                    "{:?} {:?}: {:?}",
+                    adaptation,
+                    white_point,
+                    adapted
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bradford_matches_css() {
+        // sRGB red in XYZ with the D65 to D50 matrix from CSS Color 4.
+        let red = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 0.0, 0.0));
+        let xyz = red.to_xyz_with_white_point(WhitePoint::D50, ChromaticAdaptation::Bradford);
+        let expected = ColorComponents(0.4360657, 0.2224932, 0.0139239);
+        assert!(close(&xyz, &expected, 1e-6), "{:?}", xyz);
+
+        let back =
+            Color::from_xyz_with_white_point(&xyz, WhitePoint::D50, ChromaticAdaptation::Bradford)
+                .into_color_space(ColorSpace::Srgb);
+        assert!(close(
+            back.components(),
+            &ColorComponents(1.0, 0.0, 0.0),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn constant_matrices_match_computed() {
+        for (from, to, matrix) in &BRADFORD {
+            let computed = ChromaticAdaptation::Bradford.compute_matrix(*from, *to);
+            let (a, b) = (matrix.to_array(), computed.to_array());
+            assert!(
+                a.iter().zip(&b).all(|(a, b)| (a - b).abs() < 1e-4),
+                "{:?} {:?}: {:?} != {:?}",
+                from,
+                to,
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn conversion_with_adaptation() {
+        let red = Color::new(ColorSpace::Srgb, ColorComponents(1.0, 0.0, 0.0));
+        let bradford = red.clone().into_color_space(ColorSpace::XyzD50);
+        let cat16 = red
+            .clone()
+            .into_color_space_with_adaptation(ColorSpace::XyzD50, ChromaticAdaptation::Cat16);
+
+        let expected = red.to_xyz_with_white_point(WhitePoint::D50, ChromaticAdaptation::Cat16);
+        assert!(close(cat16.components(), &expected, 1e-6));
+        assert!(!close(cat16.components(), bradford.components(), 1e-4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_y_is_rejected() {
+        WhitePoint::new(0.3, 0.0);
+    }
+
+    #[test]
+    fn same_white_point_is_not_adapted() {
+        let lab = Color::new(ColorSpace::Lab, ColorComponents(50.0, 40.0, -30.0));
+        let expected = lab.clone().into_color_space(ColorSpace::XyzD50);
+
+        for adaptation in [ChromaticAdaptation::Cat16, ChromaticAdaptation::VonKries] {
+            let xyz = lab.to_xyz_with_white_point(WhitePoint::D50, adaptation);
+            assert!(
+                close(&xyz, expected.components(), 1e-6),
+                "{:?}: {:?}",
+                adaptation,
+                xyz
+            );
+        }
+    }
+}